@@ -0,0 +1,66 @@
+// amethyst modules
+use amethyst::{
+    ecs::{Component, DenseVecStorage},
+};
+
+// local modules
+use crate::components::paddle_comp::PaddleSide;
+use crate::mx_utils::mx_timer::MxTimer;
+
+//===========
+// Constants
+//===========
+const SERVE_ANGLE: f32 = 20.;  // serve angle in degree (0 means straight to the paddle)
+
+//================
+// Ball Component
+//================
+#[derive(Clone)]
+pub struct BallComp {
+    pub speed:          f32,            // moving speed (pixel per second)
+    pub velocity:       (f32, f32),     // current velocity
+    pub serve_timer:    MxTimer,        // delay before the ball is launched
+    pub serve_to:       PaddleSide,     // the side receiving the serve
+}
+
+#[allow(dead_code)]
+impl BallComp {
+    pub fn new(speed: f32) -> Self {
+        BallComp {
+            speed,
+            velocity:       (0., 0.),
+            serve_timer:    MxTimer::default(),
+            serve_to:       PaddleSide::Bottom,
+        }
+    }
+
+    // put the ball back to the center and launch it after the delay
+    pub fn serve(&mut self, serve_to: PaddleSide, delay: f32) {
+        self.velocity = (0., 0.);
+        self.serve_to = serve_to;
+        self.serve_timer.set(delay, false);
+        self.serve_timer.start();
+    }
+
+    // launch the ball toward the receiving side with a slight angle
+    pub fn launch(&mut self) {
+        let angle = SERVE_ANGLE.to_radians();
+        match self.serve_to {
+            PaddleSide::Bottom => {
+                self.velocity = (self.speed * angle.sin(), -self.speed * angle.cos());
+            }
+            PaddleSide::Top => {
+                self.velocity = (-self.speed * angle.sin(), self.speed * angle.cos());
+            }
+        }
+    }
+
+    pub fn is_serving(&self) -> bool {
+        self.serve_timer.is_running()
+    }
+}
+
+// make it component
+impl Component for BallComp {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -9,9 +9,16 @@
 //
 
 // declare modules
-pub mod ui_glowing_comp;
-pub mod ui_swinging_comp;
 pub mod ui_cursor_comp;
 pub mod ui_cursor_option_comp;
 pub mod ui_waving_comp;
-pub mod ui_flashing_comp;
\ No newline at end of file
+pub mod ui_flashing_comp;
+pub mod ui_effects_prefab;
+pub mod paddle_comp;
+pub mod ball_comp;
+pub mod paddle_prefab;
+pub mod paddle_ai_comp;
+pub mod obstacle_comp;
+pub mod ui_progress_bar_comp;
+pub mod ui_tween_comp;
+pub mod ui_visibility_comp;
\ No newline at end of file
@@ -0,0 +1,17 @@
+// amethyst modules
+use amethyst::{
+    ecs::{Component, NullStorage},
+};
+
+//====================
+// Obstacle Component
+//====================
+//
+// Marks a ui element as a block the ball bounces off
+#[derive(Clone, Default)]
+pub struct ObstacleComp;
+
+// make it component
+impl Component for ObstacleComp {
+    type Storage = NullStorage<Self>;
+}
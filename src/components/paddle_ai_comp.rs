@@ -0,0 +1,35 @@
+// amethyst modules
+use amethyst::{
+    ecs::{Component, DenseVecStorage},
+};
+
+// local modules
+use crate::resources::ai_profiles::AiProfile;
+use crate::mx_utils::mx_timer::MxTimer;
+
+//=====================
+// Paddle AI Component
+//=====================
+#[derive(Clone)]
+pub struct PaddleAiComp {
+    pub profile:        AiProfile,      // difficulty of the cpu
+    pub reaction_timer: MxTimer,        // delay before the next decision is made
+    pub target_x:       f32,            // where the cpu wants the paddle center to be
+    pub approaching:    bool,           // was the ball coming toward the paddle at the last decision
+}
+
+impl PaddleAiComp {
+    pub fn new(profile: AiProfile) -> Self {
+        PaddleAiComp {
+            profile,
+            reaction_timer: MxTimer::default(),
+            target_x:       0.,
+            approaching:    false,
+        }
+    }
+}
+
+// make it component
+impl Component for PaddleAiComp {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -0,0 +1,119 @@
+// amethyst modules
+use amethyst::{
+    ecs::{Component, DenseVecStorage},
+};
+
+//===========
+// Constants
+//===========
+const DEFAULT_HIT_ANGLE: f32 = 60.;    // bounce angle (degree) at the paddle edge without a curve
+
+//=============
+// Paddle Side
+//=============
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PaddleSide {
+    Bottom,         // paddle defending the bottom goal
+    Top,            // paddle defending the top goal
+}
+
+impl PaddleSide {
+    pub fn opponent(self) -> Self {
+        match self {
+            PaddleSide::Bottom => PaddleSide::Top,
+            PaddleSide::Top    => PaddleSide::Bottom,
+        }
+    }
+}
+
+impl Default for PaddleSide {
+    fn default() -> Self {
+        PaddleSide::Bottom
+    }
+}
+
+//================
+// Paddle Control
+//================
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PaddleControl {
+    Player1,        // driven by the "p1_move" axis
+    Player2,        // driven by the "p2_move" axis
+    Cpu,            // driven by the computer
+}
+
+impl PaddleControl {
+    pub fn axis_name(self) -> Option<&'static str> {
+        match self {
+            PaddleControl::Player1 => Some("p1_move"),
+            PaddleControl::Player2 => Some("p2_move"),
+            PaddleControl::Cpu     => None,
+        }
+    }
+
+    // analog stick of the player's controller
+    pub fn pad_axis_name(self) -> Option<&'static str> {
+        match self {
+            PaddleControl::Player1 => Some("p1_pad_move"),
+            PaddleControl::Player2 => Some("p2_pad_move"),
+            PaddleControl::Cpu     => None,
+        }
+    }
+}
+
+impl Default for PaddleControl {
+    fn default() -> Self {
+        PaddleControl::Player1
+    }
+}
+
+//==================
+// Paddle Component
+//==================
+#[derive(Clone, new, Default)]
+pub struct PaddleComp {
+    pub side:           PaddleSide,         // which goal the paddle defends
+    pub control:        PaddleControl,      // who is moving the paddle
+    pub speed:          f32,                // max moving speed (pixel per second)
+    #[new(default)]
+    pub acceleration:   f32,                // pixel per second^2, 0 means instant speed change
+    #[new(default)]
+    pub hit_angles:     Vec<(f32, f32)>,    // (hit offset from 0.0 to 1.0, bounce angle in degree)
+    #[new(default)]
+    pub input:          f32,                // desired direction (from -1.0 to 1.0)
+    #[new(default)]
+    pub velocity:       f32,                // current moving speed
+}
+
+impl PaddleComp {
+    // bounce angle (degree) for a hit offset from -1.0 (left edge) to 1.0 (right edge)
+    pub fn hit_angle(&self, offset: f32) -> f32 {
+        let distance = offset.abs().min(1.);
+        let angle = if self.hit_angles.is_empty() {
+            distance * DEFAULT_HIT_ANGLE
+        } else {
+            // linear interpolation between the curve points
+            let mut angle = self.hit_angles[self.hit_angles.len() - 1].1;
+            let mut prev  = (0., 0.);
+            for point in self.hit_angles.iter() {
+                if distance <= point.0 {
+                    let span = point.0 - prev.0;
+                    angle = if span > 0. {
+                        prev.1 + (point.1 - prev.1) * (distance - prev.0) / span
+                    } else {
+                        point.1
+                    };
+                    break;
+                }
+                prev = *point;
+            }
+            angle
+        };
+        if offset < 0. { -angle } else { angle }
+    }
+}
+
+// make it component
+impl Component for PaddleComp {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -1,5 +1,79 @@
+// amethyst modules
+use amethyst::{
+    assets::{PrefabData, ProgressCounter},
+    ecs::{Entity, WriteStorage},
+    ui::{UiImagePrefab, UiTransform},
+    Error,
+};
+use serde::Deserialize;
 
+// local modules
+use crate::components::paddle_comp::PaddleComp;
 
-struct PaddlePrefabData {
+//====================
+// Paddle Prefab Data
+//====================
+//
+// Describes a paddle variant in resources/prefabs/paddles/*.ron
+// The prefab is instantiated on an existing paddle entity of the game ui,
+// it overrides the size, the look and the handling of the paddle.
+#[derive(Deserialize)]
+pub struct PaddlePrefabData {
+    pub name:           String,                 // used to look up the variant
+    pub width:          f32,
+    pub height:         f32,
+    pub speed:          f32,                    // max moving speed (pixel per second)
+    pub acceleration:   f32,                    // pixel per second^2, 0 means instant speed change
+    pub hit_angles:     Vec<(f32, f32)>,        // (hit offset from 0.0 to 1.0, bounce angle in degree)
+    pub image:          Option<UiImagePrefab>,  // solid color or texture of the paddle
+}
 
-}
\ No newline at end of file
+impl<'a> PrefabData<'a> for PaddlePrefabData {
+    type SystemData = (
+        WriteStorage<'a, PaddleComp>,
+        WriteStorage<'a, UiTransform>,
+        <UiImagePrefab as PrefabData<'a>>::SystemData,
+    );
+    type Result = ();
+
+    fn add_to_entity(
+        &self,
+        entity:         Entity,
+        system_data:    &mut Self::SystemData,
+        entities:       &[Entity],
+        children:       &[Entity],
+    ) -> Result<(), Error> {
+        let (ref mut paddles, ref mut transforms, ref mut image_data) = system_data;
+
+        // keep the side and the control if the entity is already a paddle
+        if !paddles.contains(entity) {
+            paddles.insert(entity, PaddleComp::default())?;
+        }
+        if let Some(paddle) = paddles.get_mut(entity) {
+            paddle.speed        = self.speed;
+            paddle.acceleration = self.acceleration;
+            paddle.hit_angles   = self.hit_angles.clone();
+        }
+
+        if let Some(tran) = transforms.get_mut(entity) {
+            tran.width  = self.width;
+            tran.height = self.height;
+        }
+
+        if let Some(ref image) = self.image {
+            image.add_to_entity(entity, image_data, entities, children)?;
+        }
+        Ok(())
+    }
+
+    fn load_sub_assets(
+        &mut self,
+        progress:       &mut ProgressCounter,
+        system_data:    &mut Self::SystemData,
+    ) -> Result<bool, Error> {
+        match self.image {
+            Some(ref mut image) => image.load_sub_assets(progress, &mut system_data.2),
+            None                => Ok(false),
+        }
+    }
+}
@@ -10,10 +10,12 @@ use amethyst::{
 pub struct UiCursorComp {
     pub group:          String,
     pub current_pos:    usize,
-    pub pos_list:       Vec<(f32, f32)>,
-    pub pos_id_list:    Vec<&'static str>,
+    pub offset:         (f32, f32),         // cursor position relative to the pointed option
+    pub pos_id_list:    Vec<String>,        // ids of the cursor options
     pub freezed:        bool,
     pub start_up:       bool,
+    #[new(default)]
+    pub shown_pos:      Option<usize>,      // option the cursor was last moved to
 }
 
 #[allow(dead_code)]
@@ -23,11 +25,11 @@ impl UiCursorComp {
     }
 
     pub fn advance_pos(&mut self, direction:bool) {
-        if self.freezed {
+        if self.freezed || self.pos_id_list.is_empty() {
             return;
         }
         if direction {
-            if self.current_pos < self.pos_list.len() - 1 {
+            if self.current_pos < self.pos_id_list.len() - 1 {
                 self.current_pos += 1;
             } else {
                 self.current_pos = 0;
@@ -36,7 +38,7 @@ impl UiCursorComp {
             if self.current_pos > 0 {
                 self.current_pos -= 1;
             } else {
-                self.current_pos = self.pos_list.len() - 1;
+                self.current_pos = self.pos_id_list.len() - 1;
             }
         }
     }
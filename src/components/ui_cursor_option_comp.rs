@@ -2,11 +2,12 @@
 use amethyst::{
     ecs::{Entity, Component, DenseVecStorage},
 };
+use serde::{Deserialize, Serialize};
 
 //=====================
 // Cursor Option Style
 //=====================
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub enum UiCursorOptionStyle {
     Glowing,
@@ -0,0 +1,291 @@
+// amethyst modules
+use amethyst::{
+    assets::PrefabData,
+    ecs::{Component, DenseVecStorage, Entity, WriteStorage},
+    ui::{ToNativeWidget, UiPrefab, UiWidget},
+    Error,
+};
+use serde::{Deserialize, Serialize};
+
+// local modules
+use crate::components::ui_cursor_option_comp::UiCursorOptionStyle;
+use crate::components::ui_progress_bar_comp::UiProgressBarStyle;
+use crate::components::ui_tween_comp::{
+    UiGlowingStyle, UiSwingingStyle, UiTween, UiTweenMode, UiTweenPreset, UiTweenProperty,
+};
+use crate::mx_utils::mx_easing::MxEasing;
+
+//=================
+// Custom UI types
+//=================
+//
+// Any widget of a ui prefab can be wrapped to declare its effects:
+//
+//      Custom(Effects(
+//          effects: (glowing: (rate: 1., intensity: 0.8)),
+//          widget:  Label(...),
+//      ))
+//
+// Effects are only attached to the wrapped widget, not to its children.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum PsUi {
+    Effects {
+        effects:    UiEffectsPrefabData,
+        widget:     UiWidget<PsUi>,
+    },
+}
+
+impl ToNativeWidget for PsUi {
+    type PrefabData = UiEffectsPrefabData;
+
+    fn to_native_widget(self, _parent_data: Self::PrefabData) -> (UiWidget<PsUi>, Self::PrefabData) {
+        match self {
+            PsUi::Effects { effects, widget } => (widget, effects),
+        }
+    }
+}
+
+// ui prefab with the effects of this game
+pub type PsUiPrefab = UiPrefab<UiEffectsPrefabData>;
+
+//=================
+// Effects Prefabs
+//=================
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiGlowingPrefab {
+    pub is_glowing:     bool,
+    pub rate:           f32,
+    pub intensity:      f32,
+    pub style:          UiGlowingStyle,
+    pub rgba_factors:   [f32; 4],
+}
+
+impl Default for UiGlowingPrefab {
+    fn default() -> Self {
+        UiGlowingPrefab {
+            is_glowing:     true,
+            rate:           1.,
+            intensity:      0.4,
+            style:          UiGlowingStyle::TwoWays,
+            rgba_factors:   [1., 1., 1., 0.],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiFlashingPrefab {
+    pub flashing_color: [f32; 4],
+    pub is_flashing:    bool,
+    pub rate:           f32,
+}
+
+impl Default for UiFlashingPrefab {
+    fn default() -> Self {
+        UiFlashingPrefab {
+            flashing_color: [1., 1., 1., 1.],
+            is_flashing:    false,
+            rate:           1.,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiSwingingPrefab {
+    pub is_swinging:    bool,
+    pub rate:           f32,
+    pub amplitude:      f32,
+    pub style:          UiSwingingStyle,
+}
+
+impl Default for UiSwingingPrefab {
+    fn default() -> Self {
+        UiSwingingPrefab {
+            is_swinging:    true,
+            rate:           1.,
+            amplitude:      1.,
+            style:          UiSwingingStyle::Horizontal,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiWavingPrefab {
+    pub group:          String,
+    pub order:          usize,      // position in the wave, 0 starts the cycle
+    pub is_waving:      bool,
+    pub rate:           f32,
+    pub height:         f32,
+    pub low_cut:        f32,
+    pub high_cut:       f32,
+    pub delay:          f32,        // seconds between two neighbours
+    pub play_time:      f32,
+    pub wait_time:      f32,
+}
+
+impl Default for UiWavingPrefab {
+    fn default() -> Self {
+        UiWavingPrefab {
+            group:          "".to_string(),
+            order:          0,
+            is_waving:      true,
+            rate:           1.,
+            height:         1.,
+            low_cut:        -1.,
+            high_cut:       1.,
+            delay:          0.,
+            play_time:      1.,
+            wait_time:      0.,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiCursorPrefab {
+    pub group:          String,
+    pub offset:         (f32, f32),         // cursor position relative to the pointed option
+    pub options:        Vec<String>,        // ids of the options, in cursor order
+}
+
+// the option id is the id of the ui transform
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiCursorOptionPrefab {
+    pub group:          String,
+    pub style:          UiCursorOptionStyle,
+}
+
+// the width of the transform is the width at 100%
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiProgressBarPrefab {
+    pub style:          UiProgressBarStyle,
+}
+
+// A preset gives the base of the tween, the other fields given in the prefab replace its values:
+//
+//      tweens: [
+//          (preset: Pulse),
+//          (id: "intro", property: Position(from: (0., 200.), to: (0., 0.)), easing: BounceOut, duration: 1.),
+//      ]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiTweenPrefab {
+    pub id:             String,
+    pub preset:         Option<UiTweenPreset>,
+    pub property:       Option<UiTweenProperty>,
+    pub easing:         Option<MxEasing>,
+    pub mode:           Option<UiTweenMode>,
+    pub duration:       Option<f32>,
+    pub delay:          f32,
+    pub is_playing:     bool,
+}
+
+impl Default for UiTweenPrefab {
+    fn default() -> Self {
+        UiTweenPrefab {
+            id:             "".to_string(),
+            preset:         None,
+            property:       None,
+            easing:         None,
+            mode:           None,
+            duration:       None,
+            delay:          0.,
+            is_playing:     true,
+        }
+    }
+}
+
+impl UiTweenPrefab {
+    pub fn to_tween(&self) -> UiTween {
+        let base = match self.preset {
+            Some(preset) => preset.to_tween(),
+            None => UiTween::new(
+                "".to_string(),
+                UiTweenProperty::default(),
+                MxEasing::default(),
+                UiTweenMode::default(),
+                1.,
+                0.,
+                true,
+            ),
+        };
+        UiTween::new(
+            self.id.clone(),
+            self.property.unwrap_or(base.property),
+            self.easing.unwrap_or(base.easing),
+            self.mode.unwrap_or(base.mode),
+            self.duration.unwrap_or(base.duration),
+            self.delay,
+            self.is_playing,
+        )
+    }
+}
+
+//=========================
+// UI Effects Prefab Data
+//=========================
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UiEffectsPrefabData {
+    pub glowing:        Option<UiGlowingPrefab>,
+    pub flashing:       Option<UiFlashingPrefab>,
+    pub swinging:       Option<UiSwingingPrefab>,
+    pub waving:         Option<UiWavingPrefab>,
+    pub cursor:         Option<UiCursorPrefab>,
+    pub cursor_option:  Option<UiCursorOptionPrefab>,
+    pub progress_bar:   Option<UiProgressBarPrefab>,
+    pub tweens:         Vec<UiTweenPrefab>,
+}
+
+impl UiEffectsPrefabData {
+    pub fn is_empty(&self) -> bool {
+        self.glowing.is_none()
+            && self.flashing.is_none()
+            && self.swinging.is_none()
+            && self.waving.is_none()
+            && self.cursor.is_none()
+            && self.cursor_option.is_none()
+            && self.progress_bar.is_none()
+            && self.tweens.is_empty()
+    }
+}
+
+// The text and the transform of the entity are written by the ui prefab at the same time,
+// so the effects are only stored here and attached by the UiEffectsSystem afterwards.
+impl<'a> PrefabData<'a> for UiEffectsPrefabData {
+    type SystemData = WriteStorage<'a, UiEffectsComp>;
+    type Result = ();
+
+    fn add_to_entity(
+        &self,
+        entity:         Entity,
+        effects:        &mut Self::SystemData,
+        _entities:      &[Entity],
+        _children:      &[Entity],
+    ) -> Result<(), Error> {
+        if !self.is_empty() {
+            effects.insert(entity, UiEffectsComp { effects: self.clone() })?;
+        }
+        Ok(())
+    }
+}
+
+//========================
+// UI Effects Component
+//========================
+//
+// Effects waiting to be attached to the entity
+#[derive(Clone)]
+pub struct UiEffectsComp {
+    pub effects:    UiEffectsPrefabData,
+}
+
+// make it component
+impl Component for UiEffectsComp {
+    type Storage = DenseVecStorage<Self>;
+}
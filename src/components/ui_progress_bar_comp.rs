@@ -0,0 +1,37 @@
+// amethyst modules
+use amethyst::{
+    ecs::{Component, DenseVecStorage},
+};
+use serde::{Deserialize, Serialize};
+
+//====================
+// Progress Bar Style
+//====================
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum UiProgressBarStyle {
+    Bar,            // the width grows from the left edge up to the prefab width
+    Percentage,     // the text shows the progress from 0% to 100%
+}
+
+impl Default for UiProgressBarStyle {
+    fn default() -> Self {
+        UiProgressBarStyle::Bar
+    }
+}
+
+//==============
+// Progress Bar
+//==============
+//
+// Follows the LoadingProgress resource
+#[derive(Clone, new)]
+pub struct UiProgressBarComp {
+    pub orginal_pos:    (f32, f32),         // saved original position
+    pub full_width:     f32,                // width at 100%
+    pub style:          UiProgressBarStyle,
+}
+
+// make it component
+impl Component for UiProgressBarComp {
+    type Storage = DenseVecStorage<Self>;
+}
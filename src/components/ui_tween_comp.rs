@@ -0,0 +1,264 @@
+// standard modules
+use std::f32::consts::PI;
+
+// amethyst modules
+use amethyst::{
+    ecs::{Component, DenseVecStorage, Entity},
+};
+use serde::{Deserialize, Serialize};
+
+// local modules
+use crate::mx_utils::mx_easing::MxEasing;
+
+//===========
+// Constants
+//===========
+pub const GLOWING_TWEEN:        &str = "glowing";       // id of the tween made from a glowing effect
+pub const SWINGING_TWEEN:       &str = "swinging";      // id of the tween made from a swinging effect
+const EFFECT_HALF_PERIOD:       f32  = PI / 5.;         // seconds of one way of the glowing and swinging at rate 1
+
+//==================
+// Tweened Property
+//==================
+//
+// Position is an offset from the original position and scale a factor of the original size,
+// color and alpha replace the text color or the solid color of the image,
+// tint is added to the original color.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum UiTweenProperty {
+    Position { from: (f32, f32), to: (f32, f32) },
+    Scale { from: f32, to: f32 },
+    Color { from: [f32; 4], to: [f32; 4] },
+    Alpha { from: f32, to: f32 },
+    Tint { from: [f32; 4], to: [f32; 4] },
+}
+
+impl Default for UiTweenProperty {
+    fn default() -> Self {
+        UiTweenProperty::Alpha { from: 1., to: 1. }
+    }
+}
+
+//============
+// Tween Mode
+//============
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum UiTweenMode {
+    Once,       // plays once and sends a UiTweenEvent
+    Loop,       // starts again from the beginning
+    PingPong,   // goes back and forth, each way takes the whole duration
+}
+
+impl Default for UiTweenMode {
+    fn default() -> Self {
+        UiTweenMode::Once
+    }
+}
+
+//================
+// Glowing Style
+//================
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[allow(dead_code)]
+pub enum UiGlowingStyle {
+    TwoWays,        // color get lightened and darkened
+    Lightening,     // color only get lightened
+    Darkening,      // color only get darkened
+}
+
+//================
+// Swinging Style
+//================
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[allow(dead_code)]
+pub enum UiSwingingStyle {
+    Horizontal,     // ui element swing horizontally
+    Vertical,       // ui element swing vertically
+}
+
+//===============
+// Tween Presets
+//===============
+//
+// The usual effects of the game, as tweens
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[allow(dead_code)]
+pub enum UiTweenPreset {
+    Swing,      // same as the horizontal swinging effect
+    Glow,       // fades a little in and out
+    Flash,      // blinks quickly
+    Pulse,      // grows and shrinks
+    FadeIn,
+    FadeOut,
+    PopIn,      // grows from nothing with an elastic overshoot
+    DropIn,     // falls from above and bounces in place
+}
+
+impl UiTweenPreset {
+    pub fn to_tween(self) -> UiTween {
+        let (property, easing, mode, duration) = match self {
+            UiTweenPreset::Swing => {
+                return UiTween::swinging(1., 1., UiSwingingStyle::Horizontal, true);
+            }
+            UiTweenPreset::Glow => {
+                return UiTween::glowing(1., 0.4, UiGlowingStyle::TwoWays, [1., 1., 1., 0.], true);
+            }
+            UiTweenPreset::Flash => (
+                UiTweenProperty::Alpha { from: 1., to: 0. },
+                MxEasing::CubicIn, UiTweenMode::PingPong, 0.12,
+            ),
+            UiTweenPreset::Pulse => (
+                UiTweenProperty::Scale { from: 1., to: 1.1 },
+                MxEasing::QuadInOut, UiTweenMode::PingPong, 0.5,
+            ),
+            UiTweenPreset::FadeIn => (
+                UiTweenProperty::Alpha { from: 0., to: 1. },
+                MxEasing::QuadOut, UiTweenMode::Once, 0.5,
+            ),
+            UiTweenPreset::FadeOut => (
+                UiTweenProperty::Alpha { from: 1., to: 0. },
+                MxEasing::QuadIn, UiTweenMode::Once, 0.5,
+            ),
+            UiTweenPreset::PopIn => (
+                UiTweenProperty::Scale { from: 0., to: 1. },
+                MxEasing::ElasticOut, UiTweenMode::Once, 0.8,
+            ),
+            UiTweenPreset::DropIn => (
+                UiTweenProperty::Position { from: (0., 200.), to: (0., 0.) },
+                MxEasing::BounceOut, UiTweenMode::Once, 1.,
+            ),
+        };
+        UiTween::new("".to_string(), property, easing, mode, duration, 0., true)
+    }
+}
+
+//=======
+// Tween
+//=======
+#[derive(Clone)]
+pub struct UiTween {
+    pub id:             String,             // sent back in the UiTweenEvent
+    pub property:       UiTweenProperty,
+    pub easing:         MxEasing,
+    pub mode:           UiTweenMode,
+    pub duration:       f32,                // seconds from the start to the end
+    pub delay:          f32,                // seconds before the first start
+    pub is_playing:     bool,
+    pub elapsed:        f32,                // seconds since the start, including the delay
+    pub is_reversed:    bool,               // going back from the end, for ping-pong
+    pub was_playing:    bool,               // playing last frame, a stopped loop puts the original back once
+}
+
+impl UiTween {
+    pub fn new(
+        id:             String,
+        property:       UiTweenProperty,
+        easing:         MxEasing,
+        mode:           UiTweenMode,
+        duration:       f32,
+        delay:          f32,
+        is_playing:     bool,
+    ) -> Self {
+        UiTween {
+            id,
+            property,
+            easing,
+            mode,
+            duration,
+            delay,
+            is_playing,
+            elapsed:        0.,
+            is_reversed:    false,
+            was_playing:    false,
+        }
+    }
+
+    // the swinging effect, a sine wave of 3 pixels times the amplitude around the original position
+    pub fn swinging(rate: f32, amplitude: f32, style: UiSwingingStyle, is_playing: bool) -> Self {
+        let offset = 3. * amplitude;
+        let (from, to) = match style {
+            UiSwingingStyle::Horizontal => ((-offset, 0.), (offset, 0.)),
+            UiSwingingStyle::Vertical   => ((0., -offset), (0., offset)),
+        };
+        UiTween::new(
+            SWINGING_TWEEN.to_string(),
+            UiTweenProperty::Position { from, to },
+            MxEasing::SineInOut,
+            UiTweenMode::PingPong,
+            EFFECT_HALF_PERIOD / rate,
+            0.,
+            is_playing,
+        )
+    }
+
+    // the glowing effect, a sine wave of the intensity times the rgba factors added to the original color
+    pub fn glowing(rate: f32, intensity: f32, style: UiGlowingStyle, rgba_factors: [f32; 4], is_playing: bool) -> Self {
+        let (low, high) = match style {
+            UiGlowingStyle::TwoWays     => (-0.5 * intensity, 0.5 * intensity),
+            UiGlowingStyle::Lightening  => (0., intensity),
+            UiGlowingStyle::Darkening   => (-intensity, 0.),
+        };
+        let tint = |factor: f32| [
+            factor * rgba_factors[0],
+            factor * rgba_factors[1],
+            factor * rgba_factors[2],
+            factor * rgba_factors[3],
+        ];
+        UiTween::new(
+            GLOWING_TWEEN.to_string(),
+            UiTweenProperty::Tint { from: tint(low), to: tint(high) },
+            MxEasing::SineInOut,
+            UiTweenMode::PingPong,
+            EFFECT_HALF_PERIOD / rate,
+            0.,
+            is_playing,
+        )
+    }
+
+    // start again from the beginning, with the delay
+    pub fn restart(&mut self) {
+        self.elapsed        = 0.;
+        self.is_reversed    = false;
+        self.is_playing     = true;
+    }
+
+    // eased progress of the value from 0 (from) to 1 (to)
+    pub fn progress(&self) -> f32 {
+        let time = (self.elapsed - self.delay).max(0.);
+        let ratio = if self.duration > 0. { (time / self.duration).min(1.) } else { 1. };
+        let ratio = if self.is_reversed { 1. - ratio } else { ratio };
+        self.easing.apply(ratio)
+    }
+}
+
+//=================
+// Tween Component
+//=================
+//
+// The tweens of an entity run together, each one on its own property.
+// The original state is saved when the component is attached,
+// a stopped looping tween puts its property back on it.
+#[derive(Clone, new)]
+pub struct UiTweenComp {
+    pub tweens:         Vec<UiTween>,
+    pub orginal_pos:    (f32, f32),         // saved original position
+    pub orginal_size:   (f32, f32),         // saved original width and height
+    pub orginal_font:   Option<f32>,        // saved original font size of a text
+    pub orginal_color:  Option<[f32; 4]>,   // saved original color of a text or a solid color image
+}
+
+// make it component
+impl Component for UiTweenComp {
+    type Storage = DenseVecStorage<Self>;
+}
+
+//=============
+// Tween Event
+//=============
+//
+// Sent on the EventChannel<UiTweenEvent> when a tween played once reaches its end
+#[derive(Clone, Debug)]
+pub struct UiTweenEvent {
+    pub entity:     Entity,
+    pub id:         String,
+}
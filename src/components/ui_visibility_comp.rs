@@ -0,0 +1,36 @@
+// amethyst modules
+use amethyst::{
+    ecs::{Component, DenseVecStorage},
+};
+
+//============
+// Visibility
+//============
+//
+// Shows or hides an entity together with all its children
+#[derive(Clone, new)]
+pub struct UiVisibilityComp {
+    pub is_visible:         bool,
+}
+
+// make it component
+impl Component for UiVisibilityComp {
+    type Storage = DenseVecStorage<Self>;
+}
+
+//========
+// Hidden
+//========
+//
+// Put by the visibility system on every hidden entity of a subtree,
+// remembers what has to be given back when it is shown again
+#[derive(Clone, new)]
+pub struct UiHiddenComp {
+    pub was_interactable:   bool,           // it reacted to the pointer before being hidden
+}
+
+// make it component
+impl Component for UiHiddenComp {
+    type Storage = DenseVecStorage<Self>;
+}
+
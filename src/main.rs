@@ -8,8 +8,13 @@ extern crate log;
 #[macro_use]
 extern crate derive_new;
 
+// standard modules
+use std::path::Path;
+
 // amethyst modules
 use amethyst::{
+    assets::PrefabLoaderSystemDesc,
+    config::Config,
     core::{frame_limiter::FrameRateLimitStrategy, transform::TransformBundle},
     input::{InputBundle, StringBindings},
     prelude::*,
@@ -30,6 +35,7 @@ use amethyst::{
         DjSystemDesc,
     },
     utils::application_root_dir,
+    window::DisplayConfig,
     LogLevelFilter,
 };
 
@@ -43,7 +49,13 @@ mod resources;
 mod mx_utils;
 use crate::states::loading_state::LoadingState;
 use crate::systems::ps_ui_bundle::PsUiBundle;
+use crate::systems::ps_game_bundle::PsGameBundle;
+use crate::systems::music_fade_system::MusicFadeSystem;
 use crate::resources::audio::Music;
+use crate::resources::key_bindings::user_bindings_path;
+use crate::resources::settings::Settings;
+use crate::components::paddle_prefab::PaddlePrefabData;
+use crate::components::ui_effects_prefab::PsUi;
 
 //===============
 // main function
@@ -65,11 +77,21 @@ fn main() -> amethyst::Result<()> {
         .unwrap()
         + "/resources";
 
-    // display configuration path
+    // the user settings are applied on top of the shipped configuration
+    let settings = Settings::load_user_settings();
+
+    // display configuration, vsync can only be chosen when the window is created
     let display_config_path = resources_dir.clone() + "/display_config.ron";
+    let mut display_config = DisplayConfig::load(display_config_path);
+    display_config.vsync = settings.vsync;
 
-    // input configuration path
-    let key_bindings_path = resources_dir.clone() + "/input.ron";
+    // input configuration path, the bindings saved by the controls screen override the shipped ones
+    let user_bindings_path = user_bindings_path();
+    let key_bindings_path = if Path::new(&user_bindings_path).exists() {
+        user_bindings_path
+    } else {
+        resources_dir.clone() + "/input.ron"
+    };
 
     // The global game data. Here we register all systems and bundles that will run for every game state.
     let game_data = GameDataBuilder::default()
@@ -86,16 +108,26 @@ fn main() -> amethyst::Result<()> {
             "dj_system",
             &[],
         )
-        // UI bundle handles UI
-        .with_bundle(UiBundle::<StringBindings>::new())?
+        // crossfade between the music playlists
+        .with(MusicFadeSystem, "music_fade_system", &["dj_system"])
+        // load paddle variants from resources/prefabs/paddles
+        .with_system_desc(
+            PrefabLoaderSystemDesc::<PaddlePrefabData>::default(),
+            "paddle_prefab_loader",
+            &[],
+        )
+        // UI bundle handles UI, the ui prefabs can declare the effects of this game
+        .with_bundle(UiBundle::<StringBindings, PsUi>::new())?
         // Add user defined UI systems
         .with_bundle(PsUiBundle)?
+        // Add user defined gameplay systems
+        .with_bundle(PsGameBundle)?
         // Add rendering graph
         .with_bundle(
             RenderingBundle::<DefaultBackend>::new()
                 // The RenderToWindow plugin provides all the scaffolding for opening a window and drawing on it
                 .with_plugin(
-                    RenderToWindow::from_config_path(display_config_path)
+                    RenderToWindow::from_config(display_config)
                         .with_clear([0.34, 0.36, 0.52, 1.0]),
                 )
                 // render 2D
@@ -110,6 +142,7 @@ fn main() -> amethyst::Result<()> {
     // and the "game_data" just created
     let mut game: Application<GameData> =
         Application::build(resources_dir, LoadingState::default())?
+            .with_resource(settings)
             .with_frame_limit(FrameRateLimitStrategy::Sleep, 60)
             .build(game_data)?;
 
@@ -1 +1,2 @@
-pub mod mx_timer;
\ No newline at end of file
+pub mod mx_timer;
+pub mod mx_easing;
\ No newline at end of file
@@ -0,0 +1,105 @@
+use std::f32::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+//==============
+// Easing Curve
+//==============
+//
+// Maps the progress of an animation (0 to 1) to the progress of the animated value.
+// Sine in-out moves like a sine wave, the glowing and swinging effects use it.
+// Elastic and bounce curves overshoot or come back, the others stay between 0 and 1.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[allow(dead_code)]
+pub enum MxEasing {
+    Linear,
+    SineIn,
+    SineOut,
+    SineInOut,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Default for MxEasing {
+    fn default() -> Self {
+        MxEasing::Linear
+    }
+}
+
+impl MxEasing {
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.min(1.).max(0.);
+        match self {
+            MxEasing::Linear        => t,
+            MxEasing::SineIn        => 1. - (t * PI * 0.5).cos(),
+            MxEasing::SineOut       => (t * PI * 0.5).sin(),
+            MxEasing::SineInOut     => (1. - (t * PI).cos()) * 0.5,
+            MxEasing::QuadIn        => t * t,
+            MxEasing::QuadOut       => 1. - (1. - t) * (1. - t),
+            MxEasing::QuadInOut     => {
+                if t < 0.5 { 2. * t * t } else { 1. - (-2. * t + 2.).powi(2) * 0.5 }
+            }
+            MxEasing::CubicIn       => t * t * t,
+            MxEasing::CubicOut      => 1. - (1. - t).powi(3),
+            MxEasing::CubicInOut    => {
+                if t < 0.5 { 4. * t * t * t } else { 1. - (-2. * t + 2.).powi(3) * 0.5 }
+            }
+            MxEasing::ElasticIn     => 1. - elastic_out(1. - t),
+            MxEasing::ElasticOut    => elastic_out(t),
+            MxEasing::ElasticInOut  => {
+                if t < 0.5 {
+                    (1. - elastic_out(1. - 2. * t)) * 0.5
+                } else {
+                    (1. + elastic_out(2. * t - 1.)) * 0.5
+                }
+            }
+            MxEasing::BounceIn      => 1. - bounce_out(1. - t),
+            MxEasing::BounceOut     => bounce_out(t),
+            MxEasing::BounceInOut   => {
+                if t < 0.5 {
+                    (1. - bounce_out(1. - 2. * t)) * 0.5
+                } else {
+                    (1. + bounce_out(2. * t - 1.)) * 0.5
+                }
+            }
+        }
+    }
+}
+
+// overshoots the end a few times before settling on it
+fn elastic_out(t: f32) -> f32 {
+    if t <= 0. {
+        0.
+    } else if t >= 1. {
+        1.
+    } else {
+        2_f32.powf(-10. * t) * ((t * 10. - 0.75) * PI * 2. / 3.).sin() + 1.
+    }
+}
+
+// falls on the end and bounces off it, each bounce lower than the previous one
+fn bounce_out(t: f32) -> f32 {
+    let (n1, d1) = (7.5625, 2.75);
+    if t < 1. / d1 {
+        n1 * t * t
+    } else if t < 2. / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984375
+    }
+}
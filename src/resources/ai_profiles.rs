@@ -0,0 +1,50 @@
+// common modules
+use std::collections::HashMap;
+
+// external modules
+use serde::{Deserialize, Serialize};
+
+//============
+// AI Profile
+//============
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AiProfile {
+    pub reaction_delay:     f32,    // seconds before the cpu reacts to a new ball direction
+    pub prediction_error:   f32,    // max error (pixel) of the predicted landing point
+    pub max_speed:          f32,    // ratio of the paddle speed the cpu is allowed to use (0.0 to 1.0)
+    pub mistake_chance:     f32,    // chance (0.0 to 1.0) to misjudge the ball completely
+}
+
+// default values
+impl Default for AiProfile {
+    fn default() -> Self {
+        AiProfile {
+            reaction_delay:     0.2,
+            prediction_error:   40.,
+            max_speed:          0.8,
+            mistake_chance:     0.1,
+        }
+    }
+}
+
+//=============
+// AI Profiles
+//=============
+//
+// Difficulty profiles loaded from resources/ai_profiles.ron
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AiProfiles {
+    pub profiles: HashMap<String, AiProfile>,
+}
+
+impl AiProfiles {
+    pub fn find(&self, name: &str) -> AiProfile {
+        match self.profiles.get(name) {
+            Some(profile) => profile.clone(),
+            None => {
+                warn!("AI profile {} is not found, use the default one", name);
+                AiProfile::default()
+            }
+        }
+    }
+}
@@ -1,109 +1,353 @@
+use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_4;
+use std::io::Cursor;
 use std::iter::Cycle;
+use std::path::Path;
 use std::vec::IntoIter;
-use std::ops::Deref;
 
 use amethyst::{
     assets::{
         AssetStorage,
         Loader,
+        ProgressCounter,
     },
     audio::{
         output::Output,
         AudioSink,
+        FlacFormat,
+        Mp3Format,
+        OggFormat,
         WavFormat,
         Source,
         SourceHandle,
     },
+    config::Config,
     ecs::{
         World, 
         WorldExt,
     },
+    utils::application_root_dir,
 };
 
-const MENU_MUSIC:       &'static [&'static str] = &["assets/sounds/theme.wav"];
-const SFX_CURSOR_TICK:  &str = "assets/sounds/hover.wav";
-const SFX_BUTTON_PUSH:  &str = "assets/sounds/push.wav";
+use rand::{thread_rng, Rng};
+use rodio::{default_output_device, source::ChannelVolume, Decoder, Device, Sink, Source as RodioSource};
+use serde::{Deserialize, Serialize};
 
-pub enum SoundType {
-    CursorTick,
-    ButtonPush,
-}
+use crate::resources::settings::Settings;
+
+pub const MENU_PLAYLIST:    &str = "menu";
+pub const MATCH_PLAYLIST:   &str = "match";
+pub const RESULTS_PLAYLIST: &str = "results";
+pub const MUSIC_CROSSFADE:  f32  = 1.5;    // seconds to fade from a playlist to the next one
+pub const SFX_CURSOR_TICK:  &str = "cursor_tick";
+pub const SFX_BUTTON_PUSH:  &str = "button_push";
+pub const SFX_PADDLE_HIT:   &str = "paddle_hit";
+pub const SFX_WALL_HIT:     &str = "wall_hit";
 
 pub struct Music {
+    pub name:  String,
     pub music: Cycle<IntoIter<SourceHandle>>,
 }
 
-pub struct Sounds {
-    pub cursor_tick: SourceHandle,
-    pub button_push: SourceHandle,
+// Named lists of music tracks, loaded from resources/audio/playlists.ron
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Playlists {
+    pub playlists: HashMap<String, Vec<String>>,
 }
 
-fn load_audio_track(loader: &Loader, world: &World, file: &str) -> SourceHandle {
-    loader.load(file, WavFormat, (), &world.read_resource())
+impl Playlists {
+    pub fn tracks(&self, name: &str) -> Vec<String> {
+        match self.playlists.get(name) {
+            Some(tracks) => tracks.clone(),
+            None => {
+                warn!("Playlist {} is not found, the music is stopped", name);
+                Vec::new()
+            }
+        }
+    }
 }
 
-pub fn initialize_audio(world: &mut World) {
-    let (sound_effects, music) = {
-        let loader = world.read_resource::<Loader>();
-        let mut sink = world.write_resource::<AudioSink>();
-        sink.set_volume(0.5);
-        sink.pause();
-        
+// The sink of the previous playlist, faded out by the music fade system
+#[derive(Default)]
+pub struct MusicFade {
+    pub old_sink:   Option<AudioSink>,
+    pub duration:   f32,
+    pub elapsed:    f32,
+}
 
+// the pitch must stay above 0, rodio can not play at speed 0
+const MAX_PITCH_VARIANCE: f32 = 0.9;
 
-        let music = MENU_MUSIC
-            .iter()
-            .map(|file| load_audio_track(&loader, &world, &file))
-            .collect::<Vec<_>>()
-            .into_iter()
-            .cycle();
+// One sound effect of resources/audio/sfx.ron
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SfxDef {
+    pub file:           String,
+    pub volume:         f32,    // multiplied by the sfx volume of the settings
+    pub pitch_variance: f32,    // max random change of the pitch, 0.1 plays from 0.9 to 1.1 speed
+}
+
+impl Default for SfxDef {
+    fn default() -> Self {
+        SfxDef {
+            file:           "".to_string(),
+            volume:         1.,
+            pitch_variance: 0.,
+        }
+    }
+}
 
-        let music = Music { music };
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SfxManifest {
+    pub effects: HashMap<String, SfxDef>,
+}
 
-        let sound = Sounds {
-            cursor_tick: load_audio_track(&loader, &world, SFX_CURSOR_TICK),
-            button_push: load_audio_track(&loader, &world, SFX_BUTTON_PUSH),
-        }; 
+pub struct SfxEntry {
+    pub handle:         SourceHandle,
+    pub volume:         f32,
+    pub pitch_variance: f32,
+}
 
-        (sound, music)
-    };
+// The loaded sound effects, played by name
+#[derive(Default)]
+pub struct SfxRegistry {
+    pub effects: HashMap<String, SfxEntry>,
+}
+
+// The amethyst output can neither change the pitch nor the balance of a sound,
+// only the effects with a pitch variance or a pan are decoded and played through rodio,
+// on a sink of the same default device
+pub struct SfxOutput {
+    pub device: Device,
+}
+
+// the format is chosen by the file extension, wav is assumed for the unknown ones
+fn load_audio_track(loader: &Loader, world: &World, file: &str, progress: &mut ProgressCounter) -> SourceHandle {
+    let extension = Path::new(file)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| extension.to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "ogg"   => loader.load(file, OggFormat, &mut *progress, &world.read_resource()),
+        "flac"  => loader.load(file, FlacFormat, &mut *progress, &world.read_resource()),
+        "mp3"   => loader.load(file, Mp3Format, &mut *progress, &world.read_resource()),
+        "wav"   => loader.load(file, WavFormat, &mut *progress, &world.read_resource()),
+        _ => {
+            warn!("Unknown audio format of {}, load it as wav", file);
+            loader.load(file, WavFormat, &mut *progress, &world.read_resource())
+        }
+    }
+}
+
+fn load_playlists() -> Playlists {
+    let playlists_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/audio/playlists.ron";
+    Playlists::load(playlists_path)
+}
+
+fn load_sfx_registry(world: &World, progress: &mut ProgressCounter) -> SfxRegistry {
+    let manifest_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/audio/sfx.ron";
+    let manifest = SfxManifest::load(manifest_path);
+    let loader = world.read_resource::<Loader>();
+    SfxRegistry {
+        effects: manifest.effects
+            .into_iter()
+            .map(|(name, effect)| {
+                if effect.pitch_variance > MAX_PITCH_VARIANCE {
+                    warn!("Pitch variance of {} is limited to {}", name, MAX_PITCH_VARIANCE);
+                }
+                let entry = SfxEntry {
+                    handle:         load_audio_track(&loader, &world, &effect.file, progress),
+                    volume:         effect.volume,
+                    pitch_variance: effect.pitch_variance.min(MAX_PITCH_VARIANCE).max(0.),
+                };
+                (name, entry)
+            })
+            .collect(),
+    }
+}
+
+fn load_music(world: &World, name: &str, tracks: &[String], progress: &mut ProgressCounter) -> Music {
+    let loader = world.read_resource::<Loader>();
+    let music = tracks
+        .iter()
+        .map(|file| load_audio_track(&loader, &world, &file, progress))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .cycle();
+    Music { name: name.to_string(), music }
+}
+
+// The menu music and the sound effects report to the progress counter of the loading state
+pub fn initialize_audio(world: &mut World, progress: &mut ProgressCounter) {
+    let playlists = load_playlists();
+    let music = load_music(world, MENU_PLAYLIST, &playlists.tracks(MENU_PLAYLIST), progress);
+    let sound_effects = load_sfx_registry(world, progress);
+    {
+        let mut sink = world.write_resource::<AudioSink>();
+        sink.set_volume(world.read_resource::<Settings>().music_volume);
+        sink.pause();
+    }
 
     world.insert(sound_effects);
     world.insert(music);
+    world.insert(playlists);
+    world.insert(MusicFade::default());
+    match default_output_device() {
+        Some(device) => world.insert(SfxOutput { device }),
+        None => warn!("No audio device is found, the varied and panned sound effects are muted"),
+    }
 }
 
-pub fn play_sound(sound_type: SoundType, sounds: &Sounds, storage: &AssetStorage<Source>, output: Option<&Output>) {
-    if let Some(ref output) = output.as_ref() {
-        match sound_type {
-            SoundType::CursorTick => {
-                if let Some(sound) = storage.get(&sounds.cursor_tick) {
-                    output.play_once(sound, 0.5);
-                }
+pub fn play_sound(
+    name:       &str,
+    registry:   &SfxRegistry,
+    storage:    &AssetStorage<Source>,
+    output:     Option<&Output>,
+    sfx_output: Option<&SfxOutput>,
+    volume:     f32,
+) {
+    if let Some(effect) = find_effect(name, registry) {
+        if let Some(sound) = storage.get(&effect.handle) {
+            if effect.pitch_variance > 0. {
+                play_varied(name, effect, sound, sfx_output, volume, None);
+            } else if let Some(output) = output {
+                output.play_once(sound, volume * effect.volume);
             }
-            SoundType::ButtonPush => {
-                if let Some(sound) = storage.get(&sounds.button_push) {
-                    output.play_once(sound, 0.5);
-                }               
+        }
+    }
+}
+
+// Same as play_sound, with a stereo balance from -1 (left speaker only) to 1 (right speaker only)
+pub fn play_sound_panned(
+    name:       &str,
+    registry:   &SfxRegistry,
+    storage:    &AssetStorage<Source>,
+    sfx_output: Option<&SfxOutput>,
+    volume:     f32,
+    pan:        f32,
+) {
+    if let Some(effect) = find_effect(name, registry) {
+        if let Some(sound) = storage.get(&effect.handle) {
+            play_varied(name, effect, sound, sfx_output, volume, Some(pan));
+        }
+    }
+}
+
+fn find_effect<'a>(name: &str, registry: &'a SfxRegistry) -> Option<&'a SfxEntry> {
+    let effect = registry.effects.get(name);
+    if effect.is_none() {
+        warn!("Sound effect {} is not found", name);
+    }
+    effect
+}
+
+// the sound is decoded again on every play, keep it to the effects the amethyst output can not play
+fn play_varied(
+    name:       &str,
+    effect:     &SfxEntry,
+    sound:      &Source,
+    sfx_output: Option<&SfxOutput>,
+    volume:     f32,
+    pan:        Option<f32>,
+) {
+    let sfx_output = match sfx_output {
+        Some(sfx_output) => sfx_output,
+        None => return,
+    };
+    let pitch = if effect.pitch_variance > 0. {
+        1. + thread_rng().gen_range(-effect.pitch_variance, effect.pitch_variance)
+    } else {
+        1.
+    };
+    match Decoder::new(Cursor::new(sound.bytes.clone())) {
+        Ok(decoder) => {
+            let sink = Sink::new(&sfx_output.device);
+            let source = decoder.speed(pitch).amplify(volume * effect.volume);
+            match pan {
+                Some(pan) => {
+                    let (left, right) = stereo_gains(pan);
+                    sink.append(ChannelVolume::new(source, vec![left, right]));
+                }
+                None => sink.append(source),
             }
+            sink.detach();
         }
+        Err(_) => error!("Sound effect {} could not be decoded", name),
     }
 }
 
-pub fn play_sfx(sound_type: SoundType, world: &mut World) {
-    let sounds       = world.read_resource::<Sounds>();
+// equal power panning, the loudness stays the same across the field
+fn stereo_gains(pan: f32) -> (f32, f32) {
+    let angle = (pan.min(1.).max(-1.) + 1.) * FRAC_PI_4;
+    (angle.cos(), angle.sin())
+}
+
+pub fn play_sfx_named(world: &World, name: &str) {
+    let registry     = world.read_resource::<SfxRegistry>();
     let storage      = world.read_resource::<AssetStorage<Source>>();
-    let audio_output = Some(world.read_resource::<Output>());
+    let audio_output = world.try_fetch::<Output>();
+    let sfx_output   = world.try_fetch::<SfxOutput>();
+    let settings     = world.read_resource::<Settings>();
     play_sound(
-        sound_type, 
-        &*sounds,
+        name,
+        &registry,
         &storage,
-        audio_output.as_ref().map(|o| o.deref()),
+        audio_output.as_ref().map(|output| &**output),
+        sfx_output.as_ref().map(|output| &**output),
+        settings.sfx_volume,
     );
 }
 
-pub fn pause_music(world: &mut World) {
-    let sink = world.write_resource::<AudioSink>();
-    sink.pause();
+// Play another playlist, the current track fades out during the crossfade
+// or is dropped immediately without it
+pub fn switch_playlist(world: &mut World, name: &str, crossfade: Option<f32>) {
+    if world.read_resource::<Music>().name == name {
+        resume_music(world);
+        return;
+    }
+
+    // the sink waits for the tracks, nothing else needs to know when they are loaded
+    let tracks = world.read_resource::<Playlists>().tracks(name);
+    let music = load_music(world, name, &tracks, &mut ProgressCounter::new());
+    world.insert(music);
+
+    // a stopped sink can not be reused, replace it with a new one
+    let new_sink = world.try_fetch::<Output>().map(|output| AudioSink::new(&output));
+    if let Some(mut new_sink) = new_sink {
+        let volume = world.read_resource::<Settings>().music_volume;
+        match crossfade {
+            Some(duration) if duration > 0. => {
+                new_sink.set_volume(0.);
+                let old_sink = world.remove::<AudioSink>();
+                world.insert(MusicFade { old_sink, duration, elapsed: 0. });
+            }
+            _ => {
+                new_sink.set_volume(volume);
+                world.insert(MusicFade::default());
+            }
+        }
+        world.insert(new_sink);
+    }
+}
+
+pub fn switch_to_menu_music(world: &mut World) {
+    switch_playlist(world, MENU_PLAYLIST, Some(MUSIC_CROSSFADE));
+}
+
+pub fn set_music_volume(world: &mut World, volume: f32) {
+    let mut sink = world.write_resource::<AudioSink>();
+    sink.set_volume(volume);
 }
 
 pub fn resume_music(world: &mut World) {
@@ -0,0 +1,172 @@
+use amethyst::{
+    assets::{Handle, Prefab},
+    core::Parent,
+    ecs::Entity,
+    prelude::*,
+    ui::{Anchor, UiImage, UiTransform},
+};
+
+// local modules
+use crate::components::paddle_comp::PaddleComp;
+use crate::components::paddle_comp::PaddleSide;
+use crate::components::paddle_comp::PaddleControl;
+use crate::components::ball_comp::BallComp;
+use crate::components::paddle_ai_comp::PaddleAiComp;
+use crate::components::obstacle_comp::ObstacleComp;
+use crate::components::paddle_prefab::PaddlePrefabData;
+use crate::resources::play_field::PlayField;
+use crate::resources::paddle_prefab_registry::PaddlePrefabRegistry;
+use crate::resources::ai_profiles::AiProfiles;
+use crate::resources::stage_registry::ObstacleDef;
+
+//===========
+// Constants
+//===========
+const OBSTACLE_COLOR: [f32; 4] = [0.5, 0.5, 0.5, 1.];
+
+pub fn impl_paddle_comp (
+    paddle_entity:  &Entity,
+    data:           &mut StateData<GameData>,
+    side:           PaddleSide,
+    control:        PaddleControl,
+    speed:          f32,
+) {
+    let mut paddle_write_storage = data.world.write_storage::<PaddleComp>();
+    let _insert_result = paddle_write_storage.insert(
+        *paddle_entity,
+        PaddleComp::new(side, control, speed),
+    );
+}
+
+pub fn impl_paddle_ai_comp (
+    paddle_entity:  &Entity,
+    data:           &mut StateData<GameData>,
+    profile_name:   &str,
+) {
+    let profile = data.world.read_resource::<AiProfiles>().find(profile_name);
+    let mut paddle_ai_write_storage = data.world.write_storage::<PaddleAiComp>();
+    let _insert_result = paddle_ai_write_storage.insert(
+        *paddle_entity,
+        PaddleAiComp::new(profile),
+    );
+}
+
+// The paddle variant is applied by the prefab loader system on the next frame
+pub fn impl_paddle_prefab (
+    paddle_entity:  &Entity,
+    data:           &mut StateData<GameData>,
+    name:           &str,
+) {
+    let paddle_prefab = data
+        .world
+        .read_resource::<PaddlePrefabRegistry>()
+        .find(data.world, name);
+    if let Some(paddle_prefab) = paddle_prefab {
+        let mut prefab_write_storage = data.world.write_storage::<Handle<Prefab<PaddlePrefabData>>>();
+        let _insert_result = prefab_write_storage.insert(*paddle_entity, paddle_prefab);
+    } else {
+        warn!("Paddle prefab {} is not found", name);
+    }
+}
+
+pub fn impl_ball_comp (
+    ball_entity:    &Entity,
+    data:           &mut StateData<GameData>,
+    speed:          f32,
+) {
+    let mut ball_write_storage = data.world.write_storage::<BallComp>();
+    let _insert_result = ball_write_storage.insert(
+        *ball_entity,
+        BallComp::new(speed),
+    );
+}
+
+pub fn set_ball_speed (
+    ball_entity:    &Entity,
+    data:           &mut StateData<GameData>,
+    speed:          f32,
+) {
+    let mut ball_storage = data.world.write_storage::<BallComp>();
+    if let Some(ball) = ball_storage.get_mut(*ball_entity) {
+        ball.speed = speed;
+    }
+}
+
+pub fn serve_ball (
+    ball_entity:    &Entity,
+    data:           &mut StateData<GameData>,
+    serve_to:       PaddleSide,
+    delay:          f32,
+) {
+    let mut ball_storage = data.world.write_storage::<BallComp>();
+    if let Some(ball) = ball_storage.get_mut(*ball_entity) {
+        ball.serve(serve_to, delay);
+    }
+}
+
+// Put the ball back to the center and hold it there until the next serve
+pub fn reset_ball (
+    ball_entity:    &Entity,
+    data:           &mut StateData<GameData>,
+) {
+    if let Some(ball) = data.world.write_storage::<BallComp>().get_mut(*ball_entity) {
+        ball.velocity = (0., 0.);
+        ball.serve_timer.reset();
+    }
+    if let Some(ui_tran) = data.world.write_storage::<UiTransform>().get_mut(*ball_entity) {
+        ui_tran.local_x = 0.;
+        ui_tran.local_y = 0.;
+    }
+}
+
+// The play field is the area between the inner sides of the two borders
+pub fn init_play_field (
+    border_left:    &Entity,
+    border_right:   &Entity,
+    data:           &mut StateData<GameData>,
+) {
+    let play_field = {
+        let ui_tran_storage = data.world.read_storage::<UiTransform>();
+        let left  = ui_tran_storage.get(*border_left).unwrap();
+        let right = ui_tran_storage.get(*border_right).unwrap();
+        PlayField::new(
+            left.local_x + left.width * 0.5,
+            right.local_x - right.width * 0.5,
+            left.local_y + left.height * 0.5,
+            left.local_y - left.height * 0.5,
+        )
+    };
+    data.world.insert(play_field);
+}
+
+pub fn set_play_field_running (
+    data:       &mut StateData<GameData>,
+    is_running: bool,
+) {
+    data.world.write_resource::<PlayField>().is_running = is_running;
+}
+
+// Obstacles are created as children of the game ui, so they are removed with it
+pub fn spawn_obstacle (
+    game_ui:        &Entity,
+    data:           &mut StateData<GameData>,
+    obstacle:       &ObstacleDef,
+    index:          usize,
+) -> Entity {
+    data.world
+        .create_entity()
+        .with(UiTransform::new(
+            format!("obstacle_{}", index),
+            Anchor::Middle,
+            Anchor::Middle,
+            obstacle.x,
+            obstacle.y,
+            1.,
+            obstacle.width,
+            obstacle.height,
+        ))
+        .with(UiImage::SolidColor(OBSTACLE_COLOR))
+        .with(Parent { entity: *game_ui })
+        .with(ObstacleComp)
+        .build()
+}
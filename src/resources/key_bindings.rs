@@ -0,0 +1,187 @@
+// amethyst modules
+use amethyst::{
+    config::ConfigError,
+    input::{Axis, Bindings, BindingError, Button, StringBindings},
+};
+
+// local modules
+use crate::components::paddle_comp::PaddleControl;
+use crate::resources::settings::{user_config_path, save_user_config};
+
+//===========
+// Constants
+//===========
+const USER_BINDINGS_FILE: &str = "input.ron";
+
+//==============
+// Binding Slot
+//==============
+//
+// The keyboard key of an axis direction or an action
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BindingSlot {
+    AxisNeg(&'static str),
+    AxisPos(&'static str),
+    Action(&'static str),
+}
+
+//==============
+// Rebind Entry
+//==============
+#[derive(Clone, Copy, Debug)]
+pub struct RebindEntry {
+    pub id:         &'static str,           // id of the option label in controls.ron
+    pub label:      &'static str,           // name shown in the conflict messages
+    pub player:     Option<PaddleControl>,  // owner of the key, None if shared by the players
+    pub slot:       BindingSlot,
+}
+
+// the entries listed on the controls screen, in cursor order
+pub const REBIND_ENTRIES: [RebindEntry; 6] = [
+    RebindEntry { id: "controls_p1_left",  label: "P1 LEFT",  player: Some(PaddleControl::Player1), slot: BindingSlot::AxisNeg("p1_move") },
+    RebindEntry { id: "controls_p1_right", label: "P1 RIGHT", player: Some(PaddleControl::Player1), slot: BindingSlot::AxisPos("p1_move") },
+    RebindEntry { id: "controls_p2_left",  label: "P2 LEFT",  player: Some(PaddleControl::Player2), slot: BindingSlot::AxisNeg("p2_move") },
+    RebindEntry { id: "controls_p2_right", label: "P2 RIGHT", player: Some(PaddleControl::Player2), slot: BindingSlot::AxisPos("p2_move") },
+    RebindEntry { id: "controls_confirm",  label: "CONFIRM",  player: None,                         slot: BindingSlot::Action("confirm") },
+    RebindEntry { id: "controls_pause",    label: "PAUSE",    player: None,                         slot: BindingSlot::Action("pause") },
+];
+
+//=================
+// Rebind Conflict
+//=================
+#[derive(Clone, Debug)]
+pub enum RebindConflict {
+    OtherPlayer(&'static str),  // the key already moves the paddle of the other player
+    AlreadyBound(String),       // the key is used by another axis or action
+}
+
+impl RebindConflict {
+    pub fn message(&self) -> String {
+        match self {
+            RebindConflict::OtherPlayer(label) => format!("KEY ALREADY USED BY {}", label),
+            RebindConflict::AlreadyBound(name) => format!("KEY ALREADY USED BY {}", name.to_uppercase()),
+        }
+    }
+}
+
+// keyboard key bound to the slot
+pub fn bound_key(bindings: &Bindings<StringBindings>, slot: BindingSlot) -> Option<Button> {
+    match slot {
+        BindingSlot::AxisNeg(axis_name) | BindingSlot::AxisPos(axis_name) => {
+            match bindings.axis(axis_name) {
+                Some(Axis::Emulated { pos, neg }) => {
+                    if let BindingSlot::AxisPos(_) = slot { Some(*pos) } else { Some(*neg) }
+                }
+                _ => None,
+            }
+        }
+        BindingSlot::Action(action_name) => {
+            // the first single key combination, the controller buttons are left alone
+            bindings
+                .action_bindings(action_name)
+                .find(|combo| combo.len() == 1 && is_key(&combo[0]))
+                .map(|combo| combo[0])
+        }
+    }
+}
+
+// a key can not be shared between player 1 and player 2
+pub fn find_conflict(
+    bindings:   &Bindings<StringBindings>,
+    entry:      &RebindEntry,
+    key:        Button,
+) -> Option<RebindConflict> {
+    for other in REBIND_ENTRIES.iter() {
+        if other.id == entry.id || bound_key(bindings, other.slot) != Some(key) {
+            continue;
+        }
+        if other.player.is_some() && entry.player.is_some() && other.player != entry.player {
+            return Some(RebindConflict::OtherPlayer(other.label));
+        }
+        return Some(RebindConflict::AlreadyBound(other.label.to_string()));
+    }
+    None
+}
+
+// replace the key of the slot, the old binding is restored on failure
+pub fn rebind_key(
+    bindings:   &mut Bindings<StringBindings>,
+    slot:       BindingSlot,
+    key:        Button,
+) -> Result<(), RebindConflict> {
+    match slot {
+        BindingSlot::AxisNeg(axis_name) | BindingSlot::AxisPos(axis_name) => {
+            let old_axis = match bindings.axis(axis_name) {
+                Some(old_axis) => old_axis.clone(),
+                None => return Ok(()),
+            };
+            let new_axis = match &old_axis {
+                Axis::Emulated { pos, neg } => {
+                    if let BindingSlot::AxisPos(_) = slot {
+                        Axis::Emulated { pos: key, neg: *neg }
+                    } else {
+                        Axis::Emulated { pos: *pos, neg: key }
+                    }
+                }
+                _ => return Ok(()),
+            };
+            bindings.remove_axis(axis_name);
+            if let Err(error) = bindings.insert_axis(axis_name, new_axis) {
+                let _insert_result = bindings.insert_axis(axis_name, old_axis);
+                return Err(binding_conflict(error));
+            }
+        }
+        BindingSlot::Action(action_name) => {
+            let old_key = bound_key(bindings, slot);
+            if let Some(old_key) = old_key {
+                let _remove_result = bindings.remove_action_binding(action_name, &[old_key]);
+            }
+            if let Err(error) = bindings.insert_action_binding(action_name.to_string(), vec![key]) {
+                if let Some(old_key) = old_key {
+                    let _insert_result = bindings.insert_action_binding(action_name.to_string(), vec![old_key]);
+                }
+                return Err(binding_conflict(error));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn binding_conflict(error: BindingError<StringBindings>) -> RebindConflict {
+    match error {
+        BindingError::ButtonBoundToAxis(name, _)
+        | BindingError::AxisButtonAlreadyBoundToAxis(name, _)
+        | BindingError::ComboAlreadyBound(name)
+        | BindingError::AxisButtonAlreadyBoundToAction(name, _) => RebindConflict::AlreadyBound(name),
+        _ => RebindConflict::AlreadyBound("ANOTHER INPUT".to_string()),
+    }
+}
+
+fn is_key(button: &Button) -> bool {
+    match button {
+        Button::Key(_) | Button::ScanCode(_) => true,
+        _ => false,
+    }
+}
+
+// text shown on the controls screen
+pub fn key_name(key: Option<Button>) -> String {
+    match key {
+        Some(Button::Key(key_code)) => format!("{:?}", key_code).to_uppercase(),
+        Some(Button::ScanCode(scan_code)) => format!("SCAN {}", scan_code),
+        _ => "-".to_string(),
+    }
+}
+
+//====================
+// User Bindings File
+//====================
+//
+// The bindings saved by the controls screen, used instead of resources/input.ron when present
+pub fn user_bindings_path() -> String {
+    user_config_path(USER_BINDINGS_FILE)
+}
+
+pub fn save_user_bindings(bindings: &Bindings<StringBindings>) -> Result<(), ConfigError> {
+    save_user_config(bindings, USER_BINDINGS_FILE)
+}
@@ -0,0 +1,22 @@
+//==================
+// Loading Progress
+//==================
+//
+// Copied from the progress counters of the loading state on every update,
+// the progress bars of the loading screen read it
+#[derive(Clone, Copy, Default)]
+pub struct LoadingProgress {
+    pub finished:   usize,      // assets loaded or failed
+    pub total:      usize,      // assets requested so far
+}
+
+impl LoadingProgress {
+    // from 0 to 1, nothing requested yet counts as nothing loaded
+    pub fn ratio(&self) -> f32 {
+        if self.total == 0 {
+            0.
+        } else {
+            (self.finished as f32 / self.total as f32).min(1.)
+        }
+    }
+}
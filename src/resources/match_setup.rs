@@ -0,0 +1,118 @@
+// local modules
+use crate::components::paddle_comp::{PaddleControl, PaddleSide};
+use crate::resources::settings::Settings;
+
+//===========
+// Constants
+//===========
+pub const DEFAULT_AI_PROFILE: &str = "normal";
+pub const DEFAULT_WIN_SCORE:  u32  = 7;
+pub const DEFAULT_TIME_LIMIT: f32  = 180.;
+
+//============
+// Match Mode
+//============
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MatchMode {
+    Arcade,         // player 1 against the cpu, stage by stage
+    OnePlayer,      // player 1 against the cpu, single match
+    TwoPlayers,     // player 1 against player 2 on a shared keyboard
+    CpuVsCpu,       // cpu against cpu, attract / spectate mode
+}
+
+impl Default for MatchMode {
+    fn default() -> Self {
+        MatchMode::Arcade
+    }
+}
+
+//=============
+// Match Setup
+//=============
+//
+// Chosen in the main menu, read by the game state when the match starts
+#[derive(Clone)]
+pub struct MatchSetup {
+    pub mode:           MatchMode,
+    pub bottom_control: PaddleControl,  // control of the bottom paddle
+    pub top_control:    PaddleControl,  // control of the top paddle
+    pub ai_profile:     String,         // difficulty of the cpu paddles
+    pub win_score:      u32,            // points needed to win a single match, 0 means no limit
+    pub time_limit:     f32,            // match duration in seconds, 0 means no limit
+}
+
+impl Default for MatchSetup {
+    fn default() -> Self {
+        MatchSetup::new(MatchMode::default())
+    }
+}
+
+impl MatchSetup {
+    pub fn new(mode: MatchMode) -> Self {
+        let (bottom_control, top_control) = match mode {
+            MatchMode::Arcade       => (PaddleControl::Player1, PaddleControl::Cpu),
+            MatchMode::OnePlayer    => (PaddleControl::Player1, PaddleControl::Cpu),
+            MatchMode::TwoPlayers   => (PaddleControl::Player1, PaddleControl::Player2),
+            MatchMode::CpuVsCpu     => (PaddleControl::Cpu,     PaddleControl::Cpu),
+        };
+        MatchSetup {
+            mode,
+            bottom_control,
+            top_control,
+            ai_profile: DEFAULT_AI_PROFILE.to_string(),
+            win_score:  DEFAULT_WIN_SCORE,
+            time_limit: DEFAULT_TIME_LIMIT,
+        }
+    }
+
+    // the single matches follow the defaults chosen in the options menu
+    pub fn from_settings(mode: MatchMode, settings: &Settings) -> Self {
+        MatchSetup {
+            ai_profile: settings.ai_profile.clone(),
+            win_score:  settings.win_score,
+            time_limit: settings.time_limit,
+            ..MatchSetup::new(mode)
+        }
+    }
+
+    // text shown on the intro label
+    pub fn title(&self) -> &'static str {
+        match self.mode {
+            MatchMode::Arcade       => "ARCADE",
+            MatchMode::OnePlayer    => "1 PLAYER",
+            MatchMode::TwoPlayers   => "2 PLAYERS",
+            MatchMode::CpuVsCpu     => "CPU V CPU",
+        }
+    }
+
+    pub fn get_control(&self, side: PaddleSide) -> PaddleControl {
+        match side {
+            PaddleSide::Bottom => self.bottom_control,
+            PaddleSide::Top    => self.top_control,
+        }
+    }
+
+    // name of the paddle owner shown on the results screen
+    pub fn player_name(&self, side: PaddleSide) -> &'static str {
+        match self.get_control(side) {
+            PaddleControl::Player1  => "PLAYER 1",
+            PaddleControl::Player2  => "PLAYER 2",
+            PaddleControl::Cpu      => {
+                // tell the two cpu paddles apart
+                if self.is_spectating() {
+                    match side {
+                        PaddleSide::Bottom => "CPU 1",
+                        PaddleSide::Top    => "CPU 2",
+                    }
+                } else {
+                    "CPU"
+                }
+            }
+        }
+    }
+
+    // nobody is playing, the match can be left any time
+    pub fn is_spectating(&self) -> bool {
+        self.mode == MatchMode::CpuVsCpu
+    }
+}
@@ -3,4 +3,16 @@
 //===================================
 pub mod ui_prefab_registry;
 pub mod ui_helper;
-pub mod audio;
\ No newline at end of file
+pub mod audio;
+pub mod play_field;
+pub mod game_helper;
+pub mod paddle_prefab_registry;
+pub mod match_setup;
+pub mod ai_profiles;
+pub mod stage_registry;
+pub mod score;
+pub mod key_bindings;
+pub mod settings;
+pub mod loading_progress;
+pub mod timeline;
+pub mod screen_fade;
\ No newline at end of file
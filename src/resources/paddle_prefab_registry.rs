@@ -0,0 +1,39 @@
+use amethyst::{
+    assets::{
+        AssetStorage,
+        Handle,
+        Prefab,
+    },
+    ecs::{
+        World,
+        WorldExt,
+    },
+};
+
+// local modules
+use crate::components::paddle_prefab::PaddlePrefabData;
+
+#[derive(Default)]
+pub struct PaddlePrefabRegistry {
+    pub prefabs: Vec<Handle<Prefab<PaddlePrefabData>>>,
+}
+
+impl PaddlePrefabRegistry {
+    pub fn find(&self, world: &World, name: &str) -> Option<Handle<Prefab<PaddlePrefabData>>> {
+        let storage = world.read_resource::<AssetStorage<Prefab<PaddlePrefabData>>>();
+        self.prefabs.iter().find_map(|handle| {
+            if storage
+                .get(handle)?
+                .entities()
+                .next()?
+                .data()?
+                .name
+                == name
+            {
+                Some(handle.clone())
+            } else {
+                None
+            }
+        })
+    }
+}
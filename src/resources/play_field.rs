@@ -0,0 +1,35 @@
+//============
+// Play Field
+//============
+//
+// Boundaries of the arcade play field, in the coordinates of the game ui container.
+// The left and right edges are the inner side of the borders, the ball bounces off them.
+// The top and bottom edges are the goal lines.
+#[derive(Clone, Default)]
+pub struct PlayField {
+    pub left:       f32,
+    pub right:      f32,
+    pub top:        f32,
+    pub bottom:     f32,
+    pub is_running: bool,       // paddles and ball are only simulated while running
+}
+
+impl PlayField {
+    pub fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        PlayField {
+            left,
+            right,
+            top,
+            bottom,
+            is_running: false,
+        }
+    }
+    // stereo balance of a sound made at this x position, -1 on the left border and 1 on the right one
+    pub fn pan(&self, x: f32) -> f32 {
+        let half_width = (self.right - self.left) * 0.5;
+        if half_width <= 0. {
+            return 0.;
+        }
+        ((x - (self.left + self.right) * 0.5) / half_width).min(1.).max(-1.)
+    }
+}
@@ -0,0 +1,96 @@
+// amethyst modules
+use amethyst::ecs::Entity;
+
+// local modules
+use crate::components::paddle_comp::PaddleSide;
+
+//=======
+// Score
+//=======
+//
+// Updated by the goal system, checked by the game state to end the match
+#[derive(Clone, Default)]
+pub struct Score {
+    pub points:     [u32; 2],   // points of the bottom and the top paddle
+    pub win_score:  u32,        // points needed to win, 0 means no limit
+    pub time_limit: f32,        // match duration in seconds, 0 means no limit
+    pub time_past:  f32,        // seconds played so far
+}
+
+impl Score {
+    pub fn new(win_score: u32, time_limit: f32) -> Self {
+        Score {
+            points: [0, 0],
+            win_score,
+            time_limit,
+            time_past: 0.,
+        }
+    }
+
+    pub fn add_point(&mut self, side: PaddleSide) {
+        match side {
+            PaddleSide::Bottom => self.points[0] += 1,
+            PaddleSide::Top    => self.points[1] += 1,
+        }
+    }
+
+    pub fn get_points(&self, side: PaddleSide) -> u32 {
+        match side {
+            PaddleSide::Bottom => self.points[0],
+            PaddleSide::Top    => self.points[1],
+        }
+    }
+
+    // seconds left before the time limit, None without a limit
+    pub fn time_remain(&self) -> Option<f32> {
+        if self.time_limit > 0. {
+            Some((self.time_limit - self.time_past).max(0.))
+        } else {
+            None
+        }
+    }
+
+    // first to the win score, or the time is up
+    pub fn is_over(&self) -> bool {
+        let score_reached = self.win_score > 0
+            && (self.points[0] >= self.win_score || self.points[1] >= self.win_score);
+        let time_is_up = self.time_limit > 0. && self.time_past >= self.time_limit;
+        score_reached || time_is_up
+    }
+
+    // the side with more points, None for a draw
+    pub fn leader(&self) -> Option<PaddleSide> {
+        if self.points[0] > self.points[1] {
+            Some(PaddleSide::Bottom)
+        } else if self.points[1] > self.points[0] {
+            Some(PaddleSide::Top)
+        } else {
+            None
+        }
+    }
+}
+
+//==============
+// Match Result
+//==============
+//
+// Handed over to the results screen when the match ends
+#[derive(Clone, Default)]
+pub struct MatchResult {
+    pub title:          String,             // e.g. "GAME OVER"
+    pub winner:         Option<PaddleSide>, // None for a draw
+    pub winner_text:    String,             // e.g. "PLAYER 1 WINS"
+    pub points:         [u32; 2],           // final points of the bottom and the top paddle
+}
+
+//===========
+// Score HUD
+//===========
+//
+// Score and match timer labels of the game ui, found once by the game state
+#[derive(Clone, Default)]
+pub struct ScoreHud {
+    pub score_bottom:   Option<Entity>,
+    pub score_top:      Option<Entity>,
+    pub match_timer:    Option<Entity>,
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use amethyst::{
+    config::Config,
+    ecs::Entity,
+    prelude::*,
+    ui::{Anchor, Stretch, UiImage, UiTransform},
+    utils::application_root_dir,
+};
+use serde::{Deserialize, Serialize};
+
+// local modules
+use crate::states::screen_fade_state::ScreenFadeState;
+
+//===========
+// Constants
+//===========
+const SCREEN_FADE_ID:       &str = "screen_fade";
+const SCREEN_FADE_Z:        f32  = 1000.;     // over every screen, the menus go up to 20
+const DEFAULT_TRANSITION:   &str = "default";
+
+//=================
+// Fade Transition
+//=================
+//
+// One entry of resources/transitions.ron
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FadeTransition {
+    pub color:      [f32; 3],       // color of the screen between the two states
+    pub fade_out:   f32,            // seconds to cover the old state
+    pub fade_in:    f32,            // seconds to uncover the new state
+}
+
+impl Default for FadeTransition {
+    fn default() -> Self {
+        FadeTransition {
+            color:      [0., 0., 0.],
+            fade_out:   0.3,
+            fade_in:    0.3,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FadeTransitions {
+    pub transitions: HashMap<String, FadeTransition>,
+}
+
+impl FadeTransitions {
+    // the unknown transitions use the default one
+    pub fn get(&self, name: &str) -> FadeTransition {
+        self.transitions
+            .get(name)
+            .or_else(|| self.transitions.get(DEFAULT_TRANSITION))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+//=============
+// Screen Fade
+//=============
+//
+// Alpha of the overlay entity, moved from one value to another by the screen fade system
+#[derive(Clone, Default)]
+pub struct ScreenFade {
+    pub overlay:    Option<Entity>,
+    pub color:      [f32; 3],
+    pub from_alpha: f32,
+    pub to_alpha:   f32,
+    pub duration:   f32,
+    pub elapsed:    f32,
+}
+
+impl ScreenFade {
+    pub fn start(&mut self, color: [f32; 3], to_alpha: f32, duration: f32) {
+        self.from_alpha = self.alpha();
+        self.color      = color;
+        self.to_alpha   = to_alpha;
+        self.duration   = duration;
+        self.elapsed    = 0.;
+    }
+
+    pub fn alpha(&self) -> f32 {
+        if self.duration <= 0. || self.elapsed >= self.duration {
+            self.to_alpha
+        } else {
+            self.from_alpha + (self.to_alpha - self.from_alpha) * self.elapsed / self.duration
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+pub fn load_fade_transitions() -> FadeTransitions {
+    let transitions_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/transitions.ron";
+    FadeTransitions::load(transitions_path)
+}
+
+// Create the overlay once, it stays for the whole game
+pub fn init_screen_fade(world: &mut World) {
+    let has_overlay = world
+        .try_fetch::<ScreenFade>()
+        .map_or(false, |screen_fade| screen_fade.overlay.is_some());
+    if has_overlay {
+        return;
+    }
+    let overlay = world
+        .create_entity()
+        .with(UiTransform::new(
+                SCREEN_FADE_ID.to_string(),
+                Anchor::Middle,
+                Anchor::Middle,
+                0.,
+                0.,
+                SCREEN_FADE_Z,
+                1280.,
+                960.,
+            )
+            .with_stretch(Stretch::XY { x_margin: 0., y_margin: 0., keep_aspect_ratio: false })
+            .into_transparent()
+        )
+        .with(UiImage::SolidColor([0., 0., 0., 0.]))
+        .build();
+    world.insert(ScreenFade {
+        overlay: Some(overlay),
+        ..ScreenFade::default()
+    });
+}
+
+// Cover the screen, switch to the next state and uncover it
+pub fn switch_with_fade(
+    world:      &World,
+    transition: &str,
+    next_state: Box<dyn State<GameData<'static, 'static>, StateEvent>>,
+) -> SimpleTrans {
+    let transition = world.read_resource::<FadeTransitions>().get(transition);
+    Trans::Push(Box::new(ScreenFadeState::fade_out(transition, next_state)))
+}
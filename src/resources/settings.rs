@@ -0,0 +1,104 @@
+// common modules
+use std::fs::create_dir_all;
+use std::path::Path;
+
+// amethyst modules
+use amethyst::{
+    config::{Config, ConfigError},
+    ecs::{World, WorldExt},
+    utils::application_root_dir,
+    window::Window,
+};
+
+// external modules
+use serde::{Deserialize, Serialize};
+
+// local modules
+use crate::resources::match_setup::{DEFAULT_AI_PROFILE, DEFAULT_WIN_SCORE, DEFAULT_TIME_LIMIT};
+
+//===========
+// Constants
+//===========
+const SETTINGS_FILE: &str = "settings.ron";
+
+//==========
+// Settings
+//==========
+//
+// Changed in the options menu, saved to config/settings.ron and applied at startup
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Settings {
+    pub music_volume:   f32,        // 0.0 to 1.0
+    pub sfx_volume:     f32,        // 0.0 to 1.0
+    pub fullscreen:     bool,
+    pub vsync:          bool,       // only read when the window is created
+    pub ai_profile:     String,     // cpu difficulty of the single matches
+    pub win_score:      u32,        // points needed to win a single match, 0 means no limit
+    pub time_limit:     f32,        // match duration in seconds, 0 means no limit
+}
+
+// default values
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            music_volume:   0.5,
+            sfx_volume:     0.5,
+            fullscreen:     false,
+            vsync:          true,
+            ai_profile:     DEFAULT_AI_PROFILE.to_string(),
+            win_score:      DEFAULT_WIN_SCORE,
+            time_limit:     DEFAULT_TIME_LIMIT,
+        }
+    }
+}
+
+impl Settings {
+    // the default settings are used until the options menu saves a file
+    pub fn load_user_settings() -> Self {
+        let path = user_config_path(SETTINGS_FILE);
+        if Path::new(&path).exists() {
+            Settings::load(path)
+        } else {
+            Settings::default()
+        }
+    }
+
+    pub fn save(&self) -> Result<(), ConfigError> {
+        save_user_config(self, SETTINGS_FILE)
+    }
+}
+
+// switch between fullscreen and windowed on the current monitor
+pub fn apply_fullscreen(world: &World, fullscreen: bool) {
+    if let Some(window) = world.try_fetch::<Window>() {
+        if fullscreen {
+            window.set_fullscreen(Some(window.get_current_monitor()));
+        } else {
+            window.set_fullscreen(None);
+        }
+    }
+}
+
+//=================
+// User Config Dir
+//=================
+//
+// The files written by the game, kept apart from the shipped resources
+pub fn user_config_path(file_name: &str) -> String {
+    application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/config/"
+        + file_name
+}
+
+pub fn save_user_config<C: Config>(config: &C, file_name: &str) -> Result<(), ConfigError> {
+    let path = user_config_path(file_name);
+    if let Some(config_dir) = Path::new(&path).parent() {
+        create_dir_all(config_dir)?;
+    }
+    config.write(path)
+}
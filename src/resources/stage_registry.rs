@@ -0,0 +1,58 @@
+// external modules
+use serde::{Deserialize, Serialize};
+
+//=====================
+// Obstacle Definition
+//=====================
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ObstacleDef {
+    pub x:      f32,
+    pub y:      f32,
+    pub width:  f32,
+    pub height: f32,
+}
+
+//==================
+// Stage Definition
+//==================
+//
+// One stage of the arcade campaign, loaded from resources/stages/*.ron
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StageDef {
+    pub title:      String,             // text of the stage intro label
+    pub ai_profile: String,             // difficulty of the cpu opponent
+    pub ball_speed: f32,                // pixel per second
+    pub obstacles:  Vec<ObstacleDef>,   // blocks placed on the play field
+    pub win_score:  u32,                // points needed to clear the stage, 0 means no limit
+    pub music:      String,             // playlist played during the stage
+}
+
+// default values
+impl Default for StageDef {
+    fn default() -> Self {
+        StageDef {
+            title:      "".to_string(),
+            ai_profile: "normal".to_string(),
+            ball_speed: 500.,
+            obstacles:  Vec::new(),
+            win_score:  0,
+            music:      "".to_string(),
+        }
+    }
+}
+
+//================
+// Stage Registry
+//================
+//
+// Stages are played in the order of their file names
+#[derive(Default)]
+pub struct StageRegistry {
+    pub stages: Vec<StageDef>,
+}
+
+impl StageRegistry {
+    pub fn get(&self, index: usize) -> Option<StageDef> {
+        self.stages.get(index).cloned()
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+
+use amethyst::{
+    ecs::{World, WorldExt},
+    shrev::{EventChannel, ReaderId},
+    utils::application_root_dir,
+    config::Config,
+};
+use serde::{Deserialize, Serialize};
+
+//================
+// Timeline Steps
+//================
+//
+// A timeline is one step, usually a sequence or a parallel group of other steps.
+// The target is the id of a ui transform, a step without target uses the target given to play_timeline.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum TimelineStep {
+    Wait(f32),                                  // seconds doing nothing
+    Flash {                                     // shows a text and flashes it with its flashing effect
+        #[serde(default)]
+        target:     String,
+        duration:   f32,
+    },
+    Show {                                      // shows an element with its children
+        #[serde(default)]
+        target:     String,
+    },
+    Hide {                                      // hides an element with its children
+        #[serde(default)]
+        target:     String,
+    },
+    Tween {                                     // plays a tween of the target once and waits for its end,
+                                                // a missing tween ends at once
+        #[serde(default)]
+        target:     String,
+        tween:      String,
+    },
+    Event(String),                              // sends a TimelineEvent with this name
+    Sequence(Vec<TimelineStep>),                // one step after the other
+    Parallel(Vec<TimelineStep>),                // all steps at once, done with the longest one
+}
+
+impl TimelineStep {
+    // give the default target to the steps without one
+    fn with_target(&self, default_target: &str) -> TimelineStep {
+        let pick = |target: &String| {
+            if target.is_empty() { default_target.to_string() } else { target.clone() }
+        };
+        match self {
+            TimelineStep::Flash { target, duration } => TimelineStep::Flash {
+                target:     pick(target),
+                duration:   *duration,
+            },
+            TimelineStep::Show { target } => TimelineStep::Show { target: pick(target) },
+            TimelineStep::Hide { target } => TimelineStep::Hide { target: pick(target) },
+            TimelineStep::Tween { target, tween } => TimelineStep::Tween {
+                target:     pick(target),
+                tween:      tween.clone(),
+            },
+            TimelineStep::Sequence(steps) => TimelineStep::Sequence(
+                steps.iter().map(|step| step.with_target(default_target)).collect()
+            ),
+            TimelineStep::Parallel(steps) => TimelineStep::Parallel(
+                steps.iter().map(|step| step.with_target(default_target)).collect()
+            ),
+            step => step.clone(),
+        }
+    }
+}
+
+// The timelines of resources/timelines.ron, by name
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TimelineDefs {
+    pub timelines: HashMap<String, TimelineStep>,
+}
+
+//==================
+// Timeline Actions
+//==================
+//
+// What the running steps ask the timeline system to do on the ui
+#[derive(Clone, Debug)]
+pub enum TimelineAction {
+    Flash(String, bool),        // target, start or stop flashing
+    Show(String),
+    Hide(String),
+    PlayTween(String, String),  // target, tween id
+}
+
+//================
+// Timeline Event
+//================
+//
+// Sent on the EventChannel<TimelineEvent> when a timeline finishes, with the timeline name,
+// and by the Event steps, with their own name
+#[derive(Clone, Debug)]
+pub struct TimelineEvent {
+    pub name:   String,
+}
+
+//===============
+// Running Steps
+//===============
+struct StepRun {
+    step:           TimelineStep,
+    children:       Vec<StepRun>,
+    current:        usize,      // running child of a sequence
+    elapsed:        f32,
+    is_started:     bool,
+    is_finished:    bool,
+}
+
+impl StepRun {
+    fn new(step: TimelineStep) -> Self {
+        let children = match &step {
+            TimelineStep::Sequence(steps) | TimelineStep::Parallel(steps) => {
+                steps.iter().cloned().map(StepRun::new).collect()
+            }
+            _ => Vec::new(),
+        };
+        StepRun {
+            step,
+            children,
+            current:        0,
+            elapsed:        0.,
+            is_started:     false,
+            is_finished:    false,
+        }
+    }
+
+    // returns true once the step is finished, the steps taking no time finish in the same frame
+    fn update(
+        &mut self,
+        delta:          f32,
+        ended_tweens:   &[(String, String)],
+        actions:        &mut Vec<TimelineAction>,
+        events:         &mut Vec<String>,
+    ) -> bool {
+        if self.is_finished {
+            return true;
+        }
+        let is_starting = !self.is_started;
+        self.is_started = true;
+
+        self.is_finished = match &self.step {
+            TimelineStep::Wait(duration) => {
+                self.elapsed += delta;
+                self.elapsed >= *duration
+            }
+            TimelineStep::Flash { target, duration } => {
+                if is_starting {
+                    actions.push(TimelineAction::Flash(target.clone(), true));
+                } else {
+                    self.elapsed += delta;
+                }
+                let is_done = self.elapsed >= *duration;
+                if is_done {
+                    actions.push(TimelineAction::Flash(target.clone(), false));
+                }
+                is_done
+            }
+            TimelineStep::Show { target } => {
+                actions.push(TimelineAction::Show(target.clone()));
+                true
+            }
+            TimelineStep::Hide { target } => {
+                actions.push(TimelineAction::Hide(target.clone()));
+                true
+            }
+            TimelineStep::Tween { target, tween } => {
+                if is_starting {
+                    actions.push(TimelineAction::PlayTween(target.clone(), tween.clone()));
+                    false
+                } else {
+                    ended_tweens
+                        .iter()
+                        .any(|(ended_target, ended_tween)| ended_target == target && ended_tween == tween)
+                }
+            }
+            TimelineStep::Event(name) => {
+                events.push(name.clone());
+                true
+            }
+            TimelineStep::Sequence(_) => {
+                while self.current < self.children.len() {
+                    let child_delta = if self.children[self.current].is_started { delta } else { 0. };
+                    if !self.children[self.current].update(child_delta, ended_tweens, actions, events) {
+                        break;
+                    }
+                    self.current += 1;
+                }
+                self.current >= self.children.len()
+            }
+            TimelineStep::Parallel(_) => {
+                let mut is_done = true;
+                for child in self.children.iter_mut() {
+                    let child_delta = if child.is_started { delta } else { 0. };
+                    is_done &= child.update(child_delta, ended_tweens, actions, events);
+                }
+                is_done
+            }
+        };
+        self.is_finished
+    }
+}
+
+struct RunningTimeline {
+    name:       String,
+    root:       StepRun,
+    is_paused:  bool,
+}
+
+//===========
+// Timelines
+//===========
+#[derive(Default)]
+pub struct Timelines {
+    defs:           HashMap<String, TimelineStep>,
+    running:        Vec<RunningTimeline>,
+    missing_tweens: Vec<(String, String)>,  // asked to play but not found, ended on the next update
+}
+
+impl Timelines {
+    pub fn new(defs: TimelineDefs) -> Self {
+        Timelines {
+            defs:           defs.timelines,
+            running:        Vec::new(),
+            missing_tweens: Vec::new(),
+        }
+    }
+
+    // start a timeline from the beginning, replacing the same one if it is running
+    pub fn play(&mut self, name: &str, target: &str) {
+        self.stop(name);
+        match self.defs.get(name) {
+            Some(step) => self.running.push(RunningTimeline {
+                name:       name.to_string(),
+                root:       StepRun::new(step.with_target(target)),
+                is_paused:  false,
+            }),
+            None => warn!("Timeline {} is not found", name),
+        }
+    }
+
+    // the steps are left as they are, a flashing text keeps flashing
+    pub fn stop(&mut self, name: &str) {
+        self.running.retain(|timeline| timeline.name != name);
+    }
+
+    pub fn set_paused(&mut self, name: &str, is_paused: bool) {
+        for timeline in self.running.iter_mut().filter(|timeline| timeline.name == name) {
+            timeline.is_paused = is_paused;
+        }
+    }
+
+    pub fn is_running(&self, name: &str) -> bool {
+        self.running.iter().any(|timeline| timeline.name == name)
+    }
+
+    // the tween step waiting for it does not wait forever
+    pub fn tween_not_found(&mut self, target: &str, tween: &str) {
+        self.missing_tweens.push((target.to_string(), tween.to_string()));
+    }
+
+    // move the running timelines forward, returns the ui actions and the events to send
+    pub fn update(&mut self, delta: f32, ended_tweens: &[(String, String)]) -> (Vec<TimelineAction>, Vec<String>) {
+        let mut actions = Vec::new();
+        let mut events  = Vec::new();
+        let mut ended_tweens = ended_tweens.to_vec();
+        ended_tweens.append(&mut self.missing_tweens);
+        for timeline in self.running.iter_mut().filter(|timeline| !timeline.is_paused) {
+            let timeline_delta = if timeline.root.is_started { delta } else { 0. };
+            if timeline.root.update(timeline_delta, &ended_tweens, &mut actions, &mut events) {
+                events.push(timeline.name.clone());
+            }
+        }
+        self.running.retain(|timeline| !timeline.root.is_finished);
+        (actions, events)
+    }
+}
+
+pub fn load_timelines() -> Timelines {
+    let timelines_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/timelines.ron";
+    Timelines::new(TimelineDefs::load(timelines_path))
+}
+
+pub fn play_timeline(world: &World, name: &str, target: &str) {
+    world.write_resource::<Timelines>().play(name, target);
+}
+
+pub fn stop_timeline(world: &World, name: &str) {
+    world.write_resource::<Timelines>().stop(name);
+}
+
+pub fn pause_timeline(world: &World, name: &str, is_paused: bool) {
+    world.write_resource::<Timelines>().set_paused(name, is_paused);
+}
+
+pub fn is_timeline_running(world: &World, name: &str) -> bool {
+    world.read_resource::<Timelines>().is_running(name)
+}
+
+pub fn register_timeline_reader(world: &World) -> ReaderId<TimelineEvent> {
+    world.write_resource::<EventChannel<TimelineEvent>>().register_reader()
+}
+
+// read the pending events, true when one of them has this name
+pub fn timeline_event_received(world: &World, reader: &mut ReaderId<TimelineEvent>, name: &str) -> bool {
+    world
+        .read_resource::<EventChannel<TimelineEvent>>()
+        .read(reader)
+        .fold(false, |received, event| received || event.name == name)
+}
@@ -1,72 +1,66 @@
 use amethyst::{
     ecs::Entity,
     prelude::*,
-    ui::{
-        UiText,
-        UiTransform,
-        UiFinder,
-    },
+    ui::{UiEvent, UiEventType, UiImage, UiText},
 };
 
 // local modules
-use crate::components::ui_glowing_comp::UiGlowingComp;
-use crate::components::ui_glowing_comp::UiGlowingStyle;
-use crate::components::ui_swinging_comp::UiSwingingComp;
-use crate::components::ui_swinging_comp::UiSwingingStyle;
 use crate::components::ui_flashing_comp::UiFlashingComp;
-use crate::components::ui_waving_comp::UiWavingComp;
 use crate::components::ui_cursor_comp::UiCursorComp;
 use crate::components::ui_cursor_option_comp::UiCursorOptionComp;
-use crate::components::ui_cursor_option_comp::UiCursorOptionStyle;
-
-pub fn impl_glowing_comp (
-    text_entity:    &Entity, 
-    data:           &mut StateData<GameData>,
-    is_glowing:    bool,
-    rate:           f32, 
-    intensity:      f32,
-    style:          UiGlowingStyle,
-    rgba_factors:   [f32; 4],
-) {
-    // get the UiText color
-    let text_color = get_text_color(text_entity, data);
+use crate::components::ui_visibility_comp::UiVisibilityComp;
+
+// color of a text or of a solid color image, a texture is drawn untinted so it counts as white
+pub fn ui_color (
+    text:           Option<&UiText>,
+    image:          Option<&UiImage>,
+) -> Option<[f32; 4]> {
+    match (text, image) {
+        (Some(text), _)                             => Some(text.color),
+        (None, Some(UiImage::SolidColor(color)))    => Some(*color),
+        (None, Some(_))                             => Some([1., 1., 1., 1.]),
+        (None, None)                                => None,
+    }
+}
 
-    // add glowing component to the entity
-    let mut glowing_comp_write_storage = data.world.write_storage::<UiGlowingComp>();
-    let _insert_result = glowing_comp_write_storage.insert(
-        *text_entity, 
-        UiGlowingComp::new(text_color, is_glowing, rate, intensity, style, rgba_factors),
-    );
+// the textures can not be tinted, returns false for them
+pub fn set_ui_color (
+    text:           Option<&mut UiText>,
+    image:          Option<&mut UiImage>,
+    color:          [f32; 4],
+) -> bool {
+    match (text, image) {
+        (Some(text), _) => {
+            text.color = color;
+            true
+        }
+        (None, Some(UiImage::SolidColor(image_color))) => {
+            *image_color = color;
+            true
+        }
+        _ => false,
+    }
 }
 
+// color of a text or an image entity, none for the other entities
 pub fn get_text_color (
-    text_entity:    &Entity,
+    ui_entity:      &Entity,
     data:           &mut StateData<GameData>,
-) -> [f32; 4]  {
-    // get the UiText color
-    let ui_text_storage = data.world.read_storage::<UiText>();
-    ui_text_storage.get(*text_entity).unwrap().color
+) -> Option<[f32; 4]>  {
+    let ui_text_storage  = data.world.read_storage::<UiText>();
+    let ui_image_storage = data.world.read_storage::<UiImage>();
+    ui_color(ui_text_storage.get(*ui_entity), ui_image_storage.get(*ui_entity))
 }
 
-pub fn impl_swinging_comp (
-    ui_entity:      &Entity,
+pub fn set_text (
+    text_entity:    &Entity,
     data:           &mut StateData<GameData>,
-    is_swinging:    bool,
-    rate:           f32,
-    amplitude:      f32,
-    style:          UiSwingingStyle,
+    text:           &str,
 ) {
-    // get the original x and y values 
-    let ui_tran_storage = data.world.read_storage::<UiTransform>();
-    let ui_item = ui_tran_storage.get(*ui_entity).unwrap();
-    let (org_x, org_y) = (ui_item.local_x, ui_item.local_y);
-
-    // add swinging component to the entity
-    let mut swinging_comp_write_storage = data.world.write_storage::<UiSwingingComp>();
-    let _insert_result = swinging_comp_write_storage.insert(
-        *ui_entity, 
-        UiSwingingComp::new((org_x, org_y), is_swinging, rate, amplitude, style),
-    );
+    let mut ui_text_storage = data.world.write_storage::<UiText>();
+    if let Some(ui_text) = ui_text_storage.get_mut(*text_entity) {
+        ui_text.text = text.to_string();
+    }
 }
 
 pub fn impl_flashing_comp (
@@ -76,91 +70,18 @@ pub fn impl_flashing_comp (
     is_flashing:    bool,
     rate:           f32,
 ) {
-    let text_color = get_text_color(ui_entity, data);
+    let text_color = match get_text_color(ui_entity, data) {
+        Some(text_color) => text_color,
+        None => {
+            warn!("Flashing effect needs a text or an image");
+            return;
+        }
+    };
 
     let mut flashing_comp_write_storage = data.world.write_storage::<UiFlashingComp>();
     let _insert_result = flashing_comp_write_storage.insert(
         *ui_entity, 
-        UiFlashingComp::new(text_color, flashing_color, is_flashing, false, rate, false),
-    );
-}
-
-pub fn impl_waving_comp (
-    ui_entity:  &Entity,
-    data:       &mut StateData<GameData>,
-    group:      &str,
-    order:      usize,
-    is_waving: bool,
-    rate:       f32,
-    height:     f32,
-    low_cut:    f32,
-    high_cut:   f32,
-    delay:      f32,
-    play_time:  f32,
-    wait_time:  f32
-) {
-    // get the original x and y values 
-    let ui_tran_storage = data.world.read_storage::<UiTransform>();
-    let ui_item = ui_tran_storage.get(*ui_entity).unwrap();
-    let (org_x, org_y) = (ui_item.local_x, ui_item.local_y);
-
-    // add swinging component to the entity
-    let mut swinging_comp_write_storage = data.world.write_storage::<UiWavingComp>();
-    let _insert_result = swinging_comp_write_storage.insert(
-        *ui_entity, 
-        UiWavingComp::new(
-            (org_x, org_y), 
-            group.to_string(), 
-            order, 
-            is_waving, 
-            rate, 
-            height, 
-            low_cut, 
-            high_cut,
-            delay,
-            play_time,
-            wait_time),
-    );
-}
-
-pub fn impl_cursor_option_comp (
-    group:          &str,
-    id:             &str,
-    ui_entity:      &Entity,
-    data:           &mut StateData<GameData>, 
-    style:          UiCursorOptionStyle,
-) {
-    let mut cursor_option_write_storage = data.world.write_storage::<UiCursorOptionComp>();
-    let _insert_result = cursor_option_write_storage.insert(
-        *ui_entity, 
-        UiCursorOptionComp::new(
-            group.to_string(), 
-            id.to_string(), 
-            style, 
-            *ui_entity
-        ),
-    );
-}
-
-pub fn impl_cursor_comp (
-    ui_entity:      &Entity,
-    data:           &mut StateData<GameData>,
-    group:          &str,
-    pos_list:       Vec<(f32, f32)>,
-    pos_id_list:    Vec<&'static str>,
-) {
-    // add cursor movement component to the entity
-    let mut cursor_write_storage = data.world.write_storage::<UiCursorComp>();
-    let _insert_result = cursor_write_storage.insert(
-        *ui_entity, 
-        UiCursorComp::new(
-            group.to_string(),
-            0, 
-            pos_list, 
-            pos_id_list,
-            false,
-            true,
-        ),
+        UiFlashingComp::new(text_color, flashing_color, is_flashing, rate, false),
     );
 }
 
@@ -175,15 +96,53 @@ pub fn move_cursor (
     } 
 }
 
-pub fn get_cursor_action (
+// move the cursor onto the option under the pointer,
+// returns false when the entity is not an option of this cursor
+pub fn point_cursor_at (
     cursor:     &Entity,
+    option:     &Entity,
     data:       &mut StateData<GameData>,
-) -> &'static str {
+) -> bool {
+    let option_storage = data.world.read_storage::<UiCursorOptionComp>();
     let mut cursor_storage = data.world.write_storage::<UiCursorComp>();
-    if let Some(cursor) = cursor_storage.get_mut(*cursor) {
-        return cursor.pos_id_list[cursor.current_pos];
+    if let (Some(cursor), Some(option)) = (cursor_storage.get_mut(*cursor), option_storage.get(*option)) {
+        if option.group != cursor.group || cursor.freezed {
+            return false;
+        }
+        if let Some(pos) = cursor.pos_id_list.iter().position(|id| *id == option.id) {
+            cursor.set_pos(pos);
+            return true;
+        }
+    }
+    false
+}
+
+// the pointer drives the same cursor as the keys,
+// returns true when an option of this cursor is clicked
+pub fn cursor_pointer_event (
+    cursor:     &Entity,
+    event:      &UiEvent,
+    data:       &mut StateData<GameData>,
+) -> bool {
+    match event.event_type {
+        UiEventType::HoverStart => {
+            point_cursor_at(cursor, &event.target, data);
+            false
+        }
+        UiEventType::Click => point_cursor_at(cursor, &event.target, data),
+        _ => false,
+    }
+}
+
+pub fn get_cursor_action (
+    cursor:     &Entity,
+    data:       &mut StateData<GameData>,
+) -> String {
+    let cursor_storage = data.world.read_storage::<UiCursorComp>();
+    if let Some(cursor) = cursor_storage.get(*cursor) {
+        return cursor.pos_id_list[cursor.current_pos].clone();
     } 
-    ""
+    "".to_string()
 }
 
 pub fn freeze_cursor (
@@ -196,113 +155,27 @@ pub fn freeze_cursor (
     }     
 }
 
-pub fn flashing_text (
-    text_entity:    &Entity,
-    data:           &mut StateData<GameData>,
-) {
-    let mut flash_storage = data.world.write_storage::<UiFlashingComp>();
-    if let Some(flashing) = flash_storage.get_mut(*text_entity) {
-        flashing.is_flashing = true;
-    }     
-}
-
-// Is there a way to set the visibility? 
-// The workaround right now is set it to transparent.
 pub fn set_text_flashing_status (
     text_entity:    &Entity,
     data:           &mut StateData<GameData>,
     flash:          bool,
     hide:           bool, 
 ) {
-    let mut flash_storage = data.world.write_storage::<UiFlashingComp>();
-    if let Some(flashing) = flash_storage.get_mut(*text_entity) {
-        flashing.is_flashing = flash;
-        flashing.is_hiding = hide;
-    }     
-}
-
-pub fn impl_bulk_waving (
-    item_ids:   Vec<&str>,
-    data:       &mut StateData<GameData>,
-    group:      &str,
-    is_waving: bool,
-    rate:       f32,
-    height:     f32,
-    low_cut:    f32,
-    high_cut:   f32,
-    delay:      f32,
-    play_time:  f32,
-    wait_time:  f32,
-) {
-    let mut order:usize = 0;
-    for item_id in item_ids {
-        if let Some(item) = data.world.exec(|ui_finder: UiFinder<'_>| {
-            ui_finder.find(item_id)
-        })  {
-            impl_waving_comp(
-                &item,
-                data,
-                group,
-                order,
-                is_waving,
-                rate,
-                height,
-                low_cut,
-                high_cut,
-                delay,
-                play_time,
-                wait_time,
-            );
-            order += 1;
+    {
+        let mut flash_storage = data.world.write_storage::<UiFlashingComp>();
+        if let Some(flashing) = flash_storage.get_mut(*text_entity) {
+            flashing.is_flashing = flash;
         }
     }
+    set_ui_visibility(text_entity, data, !hide);
 }
 
-pub fn impl_bulk_button (
-    item_ids:       Vec<&str>,
+// show or hide an entity with all its children, a hidden entity ignores the pointer
+pub fn set_ui_visibility (
+    ui_entity:      &Entity,
     data:           &mut StateData<GameData>,
-    group:          &str,
-    is_glowing:     bool,
-    glow_rate:      f32,
-    glow_intensity: f32,
-    glow_style:     UiGlowingStyle,
-    glow_rgba_ftr:  [f32; 4],
-    flashing_color: [f32; 4],
-    flash_rate:     f32,
-) -> Vec<Option<Entity>> {
-
-    let mut result:Vec<Option<Entity>> = Vec::new();
-
-    for item_id in item_ids {
-        if let Some(item) = data.world.exec(|ui_finder: UiFinder<'_>| {
-            ui_finder.find(item_id) 
-        }) {
-            result.push(Some(item));
-            impl_glowing_comp(
-                &item,
-                data,
-                is_glowing, 
-                glow_rate, 
-                glow_intensity, 
-                glow_style,
-                glow_rgba_ftr,
-            );
-            impl_cursor_option_comp(
-                group,
-                item_id,
-                &item,
-                data,
-                UiCursorOptionStyle::Glowing,
-            );
-            impl_flashing_comp(
-                &item,
-                data,
-                flashing_color,
-                false, 
-                flash_rate,
-            );
-        }
-    }
-    
-    result
-}
\ No newline at end of file
+    is_visible:     bool,
+) {
+    let mut visibility_storage = data.world.write_storage::<UiVisibilityComp>();
+    let _insert_result = visibility_storage.insert(*ui_entity, UiVisibilityComp::new(is_visible));
+}
@@ -7,17 +7,19 @@ use amethyst::{
         World, 
         WorldExt,
     },
-    ui::{UiPrefab},
 };
 
+// local modules
+use crate::components::ui_effects_prefab::PsUiPrefab;
+
 #[derive(Default)]
 pub struct UiPrefabRegistry {
-    pub prefabs: Vec<Handle<UiPrefab>>,
+    pub prefabs: Vec<Handle<PsUiPrefab>>,
 }
 
 impl UiPrefabRegistry {
-    pub fn find(&self, world: &World, name: &str) -> Option<Handle<UiPrefab>> {
-        let storage = world.read_resource::<AssetStorage<UiPrefab>>();
+    pub fn find(&self, world: &World, name: &str) -> Option<Handle<PsUiPrefab>> {
+        let storage = world.read_resource::<AssetStorage<PsUiPrefab>>();
         self.prefabs.iter().find_map(|handle| {
             if storage
                 .get(handle)?
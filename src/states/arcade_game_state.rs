@@ -1,22 +1,48 @@
 use amethyst::{
-    core::timing::Time,
     prelude::*,
     ecs::Entity,
+    input::InputEvent,
+    shrev::ReaderId,
     ui::UiFinder,
 };
 
 use crate::resources::ui_prefab_registry::UiPrefabRegistry;
 use crate::resources::ui_helper::{
     impl_flashing_comp,
-    set_text_flashing_status,
+    set_text,
 };
-use crate::mx_utils::mx_timer::MxTimer;
+use crate::resources::game_helper::*;
+use crate::resources::match_setup::{MatchSetup, MatchMode};
+use crate::resources::stage_registry::{StageRegistry, StageDef};
+use crate::resources::audio::{switch_playlist, MATCH_PLAYLIST, MUSIC_CROSSFADE};
+use crate::resources::score::{Score, MatchResult, ScoreHud};
+use crate::resources::screen_fade::switch_with_fade;
+use crate::resources::timeline::{
+    TimelineEvent, pause_timeline, play_timeline, register_timeline_reader, stop_timeline,
+    timeline_event_received,
+};
+use crate::components::paddle_comp::{PaddleSide, PaddleControl};
+use crate::states::main_menu_state::MainMenuState;
+use crate::states::results_state::ResultsState;
+use crate::states::pause_state::{PauseState, PauseAction};
 
 //===========
 // Constants
 //===========
 const ARCADE_GAME_UI:   &str = "arcade_game_ui";
-const STAGE_INFO:       &str = "stage_info";    
+const STAGE_INFO:       &str = "stage_info";
+const SCORE_BOTTOM:     &str = "score_bottom";
+const SCORE_TOP:        &str = "score_top";
+const MATCH_TIMER:      &str = "match_timer";
+const BORDER_LEFT:      &str = "border_left";
+const BORDER_RIGHT:     &str = "border_right";
+const PADDLE_1:         &str = "paddle_1";
+const PADDLE_2:         &str = "paddle_2";
+const BALL:             &str = "ball";
+const PADDLE_SPEED:     f32  = 600.;
+const PADDLE_VARIANT:   &str = "standard";
+const SERVE_DELAY:      f32  = 0.5;
+const INTRO_TIMELINE:   &str = "stage_intro";
 
 //===================
 // Define menu state
@@ -24,13 +50,25 @@ const STAGE_INFO:       &str = "stage_info";
 #[derive(Default)]
 pub struct ArcadeGameState {
     game_ui:            Option<Entity>,
+    match_setup:        MatchSetup,
+    stage:              StageDef,
+    stage_index:        usize,
     game_is_ready:      bool,
+    game_is_running:    bool,
     stage_info:         Option<Entity>,
-    transition_timer:   MxTimer,
+    ball:               Option<Entity>,
+    paddles:            Vec<(Entity, PaddleControl)>,
+    obstacles:          Vec<Entity>,
+    timeline_reader:    Option<ReaderId<TimelineEvent>>,
 }
 
 impl SimpleState for ArcadeGameState {
     fn on_start(&mut self, data: StateData<GameData>) {
+        self.match_setup = data.world.read_resource::<MatchSetup>().clone();
+        self.stage_index = 0;
+        self.stage       = self.find_stage(data.world);
+        self.reset_score(data.world);
+
         let game_ui_prefab = data
             .world
             .read_resource::<UiPrefabRegistry>()
@@ -43,15 +81,23 @@ impl SimpleState for ArcadeGameState {
                 .build()
             );
         }
-        self.transition_timer.set(3.5, false);
+        self.timeline_reader = Some(register_timeline_reader(data.world));
+        self.play_stage_music(data.world);
     }
 
-    fn on_stop(&mut self, data: StateData<GameData>) {
-        self.game_is_ready = false;
+    fn on_stop(&mut self, mut data: StateData<GameData>) {
+        self.game_is_ready   = false;
+        self.game_is_running = false;
+        set_play_field_running(&mut data, false);
+        stop_timeline(data.world, INTRO_TIMELINE);
+        data.world.insert(ScoreHud::default());
         if let Some(game_ui) = self.game_ui {
             if data.world.delete_entity(game_ui).is_ok() {
                 self.stage_info = None;
+                self.ball       = None;
                 self.game_ui    = None;
+                self.paddles.clear();
+                self.obstacles.clear();
             }
         }
     }
@@ -61,22 +107,245 @@ impl SimpleState for ArcadeGameState {
 
         if !self.game_is_ready {
             if self.game_ui.is_some() {
-                self.stage_info = data.world.exec(|ui_finder: UiFinder<'_>| {
-                    ui_finder.find(STAGE_INFO) 
-                }); 
+                let (stage_info, score_hud) = data.world.exec(|ui_finder: UiFinder<'_>| {
+                    (ui_finder.find(STAGE_INFO), ScoreHud {
+                        score_bottom:   ui_finder.find(SCORE_BOTTOM),
+                        score_top:      ui_finder.find(SCORE_TOP),
+                        match_timer:    ui_finder.find(MATCH_TIMER),
+                    })
+                });
+                self.stage_info = stage_info;
+                data.world.insert(score_hud);
                 self.game_is_ready = true;
-                if let Some(stage_info) = self.stage_info {   
-                    impl_flashing_comp(&stage_info, data, [1., 1., 0., 1.], true, 0.2);
+                if let Some(stage_info) = self.stage_info {
+                    set_text(&stage_info, data, &self.stage.title);
+                    impl_flashing_comp(&stage_info, data, [1., 1., 0., 1.], false, 0.2);
                 }
-                self.transition_timer.start();
+                self.setup_game_objects(data);
+                play_timeline(data.world, INTRO_TIMELINE, STAGE_INFO);
             }
             self.game_is_ready = true;
-        } else if self.transition_timer.update(&*data.world.read_resource::<Time>()) {
-            if let Some(stage_info) = self.stage_info {  
-                set_text_flashing_status(&stage_info, data, false, true);
+        } else {
+            // the ball is served once the stage title is gone
+            let intro_is_over = match self.timeline_reader {
+                Some(ref mut timeline_reader) => timeline_event_received(data.world, timeline_reader, INTRO_TIMELINE),
+                None => false,
+            };
+            if intro_is_over {
+                self.start_game(data);
+            }
+        }
+
+        // act on the choice made in the pause menu
+        let pause_action = *data.world.read_resource::<PauseAction>();
+        data.world.insert(PauseAction::Resume);
+        match pause_action {
+            PauseAction::Restart    => {
+                return switch_with_fade(data.world, "to_arcade", Box::new(ArcadeGameState::default()));
+            }
+            PauseAction::QuitToMenu => {
+                return switch_with_fade(data.world, "to_main_menu", Box::new(MainMenuState::default()));
             }
+            PauseAction::Resume     => {}
+        }
+
+        if self.game_is_running && data.world.read_resource::<Score>().is_over() {
+            return self.end_match(data);
         }
 
         Trans::None
     }
+
+    // freeze the ball, the paddles, the match clock and the intro timeline while the pause menu is open
+    fn on_pause(&mut self, mut data: StateData<GameData>) {
+        set_play_field_running(&mut data, false);
+        pause_timeline(data.world, INTRO_TIMELINE, true);
+    }
+
+    fn on_resume(&mut self, mut data: StateData<GameData>) {
+        set_play_field_running(&mut data, self.game_is_running);
+        pause_timeline(data.world, INTRO_TIMELINE, false);
+    }
+
+    fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+        match event {
+            StateEvent::Input(input_event) => {
+                if let InputEvent::ActionPressed(action) = input_event {
+                    if action == "pause" && self.game_is_ready {
+                        return Trans::Push(Box::new(PauseState::default()));
+                    }
+                    // spectators can go back to the menu any time
+                    if action == "confirm" && self.match_setup.is_spectating() {
+                        return switch_with_fade(data.world, "to_main_menu", Box::new(MainMenuState::default()));
+                    }
+                    Trans::None
+                } else {
+                    Trans::None
+                }
+            },
+            _ => Trans::None,
+        }
+    }
+}
+
+impl ArcadeGameState {
+    // arcade mode follows the stage files, the other modes play a single free match
+    fn find_stage(&self, world: &World) -> StageDef {
+        if self.match_setup.mode == MatchMode::Arcade {
+            if let Some(stage) = world.read_resource::<StageRegistry>().get(self.stage_index) {
+                return stage;
+            }
+            warn!("Stage {} is not defined", self.stage_index + 1);
+        }
+        StageDef {
+            title:      self.match_setup.title().to_string(),
+            ai_profile: self.match_setup.ai_profile.clone(),
+            win_score:  self.match_setup.win_score,
+            ..StageDef::default()
+        }
+    }
+
+    fn reset_score(&self, world: &mut World) {
+        world.insert(Score::new(self.stage.win_score, self.match_setup.time_limit));
+    }
+
+    // the single matches and the stages without music share the match playlist
+    fn play_stage_music(&self, world: &mut World) {
+        if self.stage.music.is_empty() {
+            switch_playlist(world, MATCH_PLAYLIST, Some(MUSIC_CROSSFADE));
+        } else {
+            switch_playlist(world, &self.stage.music, Some(MUSIC_CROSSFADE));
+        }
+    }
+
+    // attach the gameplay components to the entities of the game ui
+    fn setup_game_objects(&mut self, data: &mut StateData<GameData>) {
+        let (border_left, border_right, paddle_1, paddle_2, ball) = data.world.exec(|ui_finder: UiFinder<'_>| {
+            (
+                ui_finder.find(BORDER_LEFT),
+                ui_finder.find(BORDER_RIGHT),
+                ui_finder.find(PADDLE_1),
+                ui_finder.find(PADDLE_2),
+                ui_finder.find(BALL),
+            )
+        });
+
+        if let (Some(border_left), Some(border_right)) = (border_left, border_right) {
+            init_play_field(&border_left, &border_right, data);
+        }
+        if let Some(paddle_1) = paddle_1 {
+            let control = self.match_setup.bottom_control;
+            impl_paddle_comp(&paddle_1, data, PaddleSide::Bottom, control, PADDLE_SPEED);
+            impl_paddle_prefab(&paddle_1, data, PADDLE_VARIANT);
+            self.paddles.push((paddle_1, control));
+        }
+        if let Some(paddle_2) = paddle_2 {
+            let control = self.match_setup.top_control;
+            impl_paddle_comp(&paddle_2, data, PaddleSide::Top, control, PADDLE_SPEED);
+            impl_paddle_prefab(&paddle_2, data, PADDLE_VARIANT);
+            self.paddles.push((paddle_2, control));
+        }
+        if let Some(ball) = ball {
+            impl_ball_comp(&ball, data, self.stage.ball_speed);
+        }
+        self.ball = ball;
+        self.apply_stage(data);
+    }
+
+    // apply the cpu profile, the ball speed and the obstacles of the current stage
+    fn apply_stage(&mut self, data: &mut StateData<GameData>) {
+        for (paddle, control) in self.paddles.iter() {
+            if *control == PaddleControl::Cpu {
+                impl_paddle_ai_comp(paddle, data, &self.stage.ai_profile);
+            }
+        }
+        if let Some(ball) = self.ball {
+            set_ball_speed(&ball, data, self.stage.ball_speed);
+        }
+
+        let _delete_result = data.world.delete_entities(&self.obstacles);
+        self.obstacles.clear();
+        if let Some(game_ui) = self.game_ui {
+            for (index, obstacle) in self.stage.obstacles.iter().enumerate() {
+                self.obstacles.push(spawn_obstacle(&game_ui, data, obstacle, index));
+            }
+        }
+    }
+
+    // player 1 defends the bottom goal in the arcade mode
+    fn end_match(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        let leader = data.world.read_resource::<Score>().leader();
+        if self.match_setup.mode == MatchMode::Arcade {
+            if leader == Some(PaddleSide::Bottom) {
+                self.clear_stage(data)
+            } else {
+                info!("Game over at {}", self.stage.title);
+                self.show_results(data, "GAME OVER", leader)
+            }
+        } else {
+            self.show_results(data, self.match_setup.title(), leader)
+        }
+    }
+
+    fn show_results(
+        &mut self,
+        data:   &mut StateData<GameData>,
+        title:  &str,
+        winner: Option<PaddleSide>,
+    ) -> SimpleTrans {
+        let winner_text = match winner {
+            Some(side) => format!("{} WINS", self.match_setup.player_name(side)),
+            None       => "DRAW".to_string(),
+        };
+        let points = data.world.read_resource::<Score>().points;
+        data.world.insert(MatchResult {
+            title: title.to_string(),
+            winner,
+            winner_text,
+            points,
+        });
+        switch_with_fade(data.world, "to_results", Box::new(ResultsState::default()))
+    }
+
+    // move on to the next stage, the campaign ends after the last one
+    fn clear_stage(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        self.stage_index += 1;
+        let next_stage = data.world.read_resource::<StageRegistry>().get(self.stage_index);
+        match next_stage {
+            Some(stage) => {
+                self.stage           = stage;
+                self.game_is_running = false;
+                self.reset_score(data.world);
+                set_play_field_running(data, false);
+                if let Some(ball) = self.ball {
+                    reset_ball(&ball, data);
+                }
+                self.apply_stage(data);
+                self.play_stage_music(data.world);
+
+                // replay the intro with the new stage title
+                if let Some(stage_info) = self.stage_info {
+                    set_text(&stage_info, data, &self.stage.title);
+                }
+                play_timeline(data.world, INTRO_TIMELINE, STAGE_INFO);
+                Trans::None
+            }
+            None => {
+                info!("All stages cleared");
+                self.show_results(data, "ALL STAGES CLEARED", Some(PaddleSide::Bottom))
+            }
+        }
+    }
+
+    // release the paddles and serve the first ball
+    fn start_game(&mut self, data: &mut StateData<GameData>) {
+        if self.game_is_running {
+            return;
+        }
+        self.game_is_running = true;
+        set_play_field_running(data, true);
+        if let Some(ball) = self.ball {
+            serve_ball(&ball, data, PaddleSide::Bottom, SERVE_DELAY);
+        }
+    }
 }
@@ -0,0 +1,224 @@
+// amethyst modules
+use amethyst::{
+    ecs::Entity,
+    prelude::*,
+    input::{Button, InputEvent, InputHandler, StringBindings, VirtualKeyCode},
+    ui::UiFinder,
+};
+
+// local modules
+use crate::resources::ui_prefab_registry::UiPrefabRegistry;
+use crate::resources::ui_helper::*;
+use crate::resources::audio::{SFX_BUTTON_PUSH, play_sfx_named};
+use crate::resources::key_bindings::{
+    REBIND_ENTRIES, bound_key, find_conflict, key_name, rebind_key, save_user_bindings,
+};
+
+//===========
+// Constants
+//===========
+const CONTROLS:         &str = "controls";
+const BUTTON_BACK:      &str = "button_controls_back";
+const CURSOR:           &str = "controls_cursor";
+const MESSAGE:          &str = "controls_message";
+const KEY_LABEL_SUFFIX: &str = "_key";
+const HELP_MESSAGE:     &str = "SELECT AN ACTION TO CHANGE ITS KEY";
+
+//=======================
+// Define controls state
+//=======================
+//
+// Pushed over the options menu, every change is saved to the user bindings file right away
+#[derive(Default)]
+pub struct ControlsState {
+    controls_screen:        Option<Entity>,
+    controls_cursor:        Option<Entity>,
+    controls_message:       Option<Entity>,
+    key_labels:             Vec<Option<Entity>>,
+    controls_is_ready:      bool,
+    waiting_entry:          Option<usize>,  // entry waiting for a key press
+    key_captured:           bool,           // the key press of this frame is already used
+}
+
+impl SimpleState for ControlsState {
+    fn on_start(&mut self, data: StateData<GameData>) {
+        let controls_prefab = data
+            .world
+            .read_resource::<UiPrefabRegistry>()
+            .find(data.world, CONTROLS);
+        if let Some(controls_prefab) = controls_prefab {
+            self.controls_screen = Some(data
+                .world
+                .create_entity()
+                .with(controls_prefab)
+                .build()
+            );
+        }
+    }
+
+    fn on_stop(&mut self, data: StateData<GameData>) {
+        self.controls_is_ready = false;
+        self.waiting_entry = None;
+        if let Some(controls_screen) = self.controls_screen {
+            if data.world.delete_entity(controls_screen).is_ok() {
+                self.key_labels.clear();
+                self.controls_cursor  = None;
+                self.controls_message = None;
+                self.controls_screen  = None;
+            }
+        }
+    }
+
+    fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        data.data.update(&data.world);
+        self.key_captured = false;
+
+        if !self.controls_is_ready && self.controls_screen.is_some() {
+            let (cursor, message, key_labels) = data.world.exec(|ui_finder: UiFinder<'_>| {
+                (
+                    ui_finder.find(CURSOR),
+                    ui_finder.find(MESSAGE),
+                    REBIND_ENTRIES
+                        .iter()
+                        .map(|entry| ui_finder.find(&format!("{}{}", entry.id, KEY_LABEL_SUFFIX)))
+                        .collect::<Vec<_>>(),
+                )
+            });
+            self.controls_cursor    = cursor;
+            self.controls_message   = message;
+            self.key_labels         = key_labels;
+            self.controls_is_ready  = cursor.is_some();
+            if self.controls_is_ready {
+                self.refresh_key_labels(data);
+                self.show_message(data, HELP_MESSAGE);
+            }
+        }
+        Trans::None
+    }
+
+    fn handle_event(&mut self, mut data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+        if !self.controls_is_ready {
+            return Trans::None;
+        }
+        match event {
+            StateEvent::Input(InputEvent::KeyPressed { key_code, .. }) => {
+                if let Some(entry_index) = self.waiting_entry {
+                    // the action bound to this key must not trigger as well
+                    self.key_captured = true;
+                    if key_code == VirtualKeyCode::Escape {
+                        self.stop_waiting(&mut data, HELP_MESSAGE.to_string());
+                    } else {
+                        self.capture_key(&mut data, entry_index, Button::Key(key_code));
+                    }
+                }
+                Trans::None
+            },
+            StateEvent::Input(InputEvent::ActionPressed(action)) => {
+                if self.waiting_entry.is_some() || self.key_captured {
+                    return Trans::None;
+                }
+                if action == "back" || action == "pause" {
+                    return Trans::Pop;
+                } else if action == "confirm" {
+                    return self.confirm_option(&mut data);
+                } else if action == "ui_up" {
+                    if let Some(cursor) = self.controls_cursor {
+                        move_cursor(&cursor, &mut data, false);
+                    }
+                } else if action == "ui_down" {
+                    if let Some(cursor) = self.controls_cursor {
+                        move_cursor(&cursor, &mut data, true);
+                    }
+                }
+                Trans::None
+            },
+            StateEvent::Ui(ui_event) => {
+                if self.waiting_entry.is_some() {
+                    return Trans::None;
+                }
+                if let Some(cursor) = self.controls_cursor {
+                    if cursor_pointer_event(&cursor, &ui_event, &mut data) {
+                        return self.confirm_option(&mut data);
+                    }
+                }
+                Trans::None
+            },
+            _ => Trans::None,
+        }
+    }
+}
+
+impl ControlsState {
+    // start waiting for the new key of the pointed entry
+    fn confirm_option(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        play_sfx_named(data.world, SFX_BUTTON_PUSH);
+        if let Some(cursor) = self.controls_cursor {
+            let action = get_cursor_action(&cursor, data);
+            if action == BUTTON_BACK {
+                return Trans::Pop;
+            }
+            if let Some(entry_index) = REBIND_ENTRIES.iter().position(|entry| entry.id == action) {
+                self.waiting_entry = Some(entry_index);
+                if let Some(Some(key_label)) = self.key_labels.get(entry_index) {
+                    set_text_flashing_status(key_label, data, true, false);
+                }
+                let message = format!("PRESS A KEY FOR {}, ESC TO CANCEL", REBIND_ENTRIES[entry_index].label);
+                self.show_message(data, &message);
+            }
+        }
+        Trans::None
+    }
+
+    fn capture_key(&mut self, data: &mut StateData<GameData>, entry_index: usize, key: Button) {
+        let entry = REBIND_ENTRIES[entry_index];
+        let message = {
+            let mut input = data.world.write_resource::<InputHandler<StringBindings>>();
+            let result = match find_conflict(&input.bindings, &entry, key) {
+                Some(conflict) => Err(conflict),
+                None => rebind_key(&mut input.bindings, entry.slot, key),
+            };
+            match result {
+                Ok(()) => match save_user_bindings(&input.bindings) {
+                    Ok(()) => format!("{} IS NOW {}", entry.label, key_name(Some(key))),
+                    Err(error) => {
+                        warn!("Failed to save the key bindings: {}", error);
+                        "THE KEYS COULD NOT BE SAVED".to_string()
+                    }
+                },
+                Err(conflict) => conflict.message(),
+            }
+        };
+        self.refresh_key_labels(data);
+        self.stop_waiting(data, message);
+    }
+
+    fn stop_waiting(&mut self, data: &mut StateData<GameData>, message: String) {
+        if let Some(entry_index) = self.waiting_entry.take() {
+            if let Some(Some(key_label)) = self.key_labels.get(entry_index) {
+                set_text_flashing_status(key_label, data, false, false);
+            }
+        }
+        self.show_message(data, &message);
+    }
+
+    fn refresh_key_labels(&self, data: &mut StateData<GameData>) {
+        let key_names = {
+            let input = data.world.read_resource::<InputHandler<StringBindings>>();
+            REBIND_ENTRIES
+                .iter()
+                .map(|entry| key_name(bound_key(&input.bindings, entry.slot)))
+                .collect::<Vec<_>>()
+        };
+        for (key_label, name) in self.key_labels.iter().zip(key_names.iter()) {
+            if let Some(key_label) = key_label {
+                set_text(key_label, data, name);
+            }
+        }
+    }
+
+    fn show_message(&self, data: &mut StateData<GameData>, message: &str) {
+        if let Some(controls_message) = self.controls_message {
+            set_text(&controls_message, data, message);
+        }
+    }
+}
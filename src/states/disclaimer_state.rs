@@ -7,20 +7,17 @@ use amethyst::{
     ecs::Entity,
     prelude::*,
     input::InputEvent,
-    ui::UiFinder,
 };
 
 // local modules
 use crate::states::main_menu_state::MainMenuState;
-use crate::components::ui_glowing_comp::UiGlowingStyle;
 use crate::resources::ui_prefab_registry::UiPrefabRegistry;
-use crate::resources::ui_helper::impl_glowing_comp;
+use crate::resources::screen_fade::switch_with_fade;
 
 //===========
 // Constants
 //===========
 const DISCLAIMER_ID: &str = "disclaimer";
-const INSTRUCTION:   &str = "instruction";
 
 //=========================
 // Define disclaimer state
@@ -29,8 +26,6 @@ const INSTRUCTION:   &str = "instruction";
 pub struct DisclaimerState {
     // Loading screen entity
     disclaimer_screen:      Option<Entity>,
-    disclaimer_instruct:    Option<Entity>,
-    disclaimer_is_ready:    bool,
 }
 
 impl SimpleState for DisclaimerState {
@@ -52,45 +47,25 @@ impl SimpleState for DisclaimerState {
     }
 
     fn on_stop(&mut self, data: StateData<GameData>) {
-        self.disclaimer_is_ready = false;
         if let Some(disclaimer_screen) = self.disclaimer_screen {
             if data.world.delete_entity(disclaimer_screen).is_ok() {
-                self.disclaimer_instruct = None;
-                self.disclaimer_screen   = None;
+                self.disclaimer_screen = None;
             }
         }
     }
 
     fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
         data.data.update(&data.world);
-        if !self.disclaimer_is_ready {
-            if self.disclaimer_screen.is_some() {
-                self.disclaimer_instruct = data.world.exec(|ui_finder: UiFinder<'_>| {
-                    ui_finder.find(INSTRUCTION) 
-                });
-                if let Some(instruction) = self.disclaimer_instruct {   
-                    impl_glowing_comp(
-                        &instruction, 
-                        data, 
-                        true, 
-                        1., 
-                        0.8, 
-                        UiGlowingStyle::Darkening, 
-                        [1., 1., 0., 0.]
-                    );
-                }
-                self.disclaimer_is_ready = true;
-            }
-        }
+        // the instruction glowing is declared in disclaimer.ron
         Trans::None
     }
 
-    fn handle_event(&mut self, _data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+    fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
         match event {
             StateEvent::Input(input_event) => {
                 if let InputEvent::ActionPressed(action) = input_event {
                     if action == "confirm" {
-                        return Trans::Switch(Box::new(MainMenuState::default()));
+                        return switch_with_fade(data.world, "to_main_menu", Box::new(MainMenuState::default()));
                     }
                     Trans::None
                 } else {
@@ -0,0 +1,157 @@
+// amethyst modules
+use amethyst::{
+    ecs::Entity,
+    prelude::*,
+    input::InputEvent,
+    ui::UiFinder,
+};
+
+// local modules
+use crate::states::loading_state::LoadingState;
+use crate::resources::ui_prefab_registry::UiPrefabRegistry;
+use crate::resources::ui_helper::*;
+use crate::resources::audio::{SFX_BUTTON_PUSH, play_sfx_named};
+
+//===========
+// Constants
+//===========
+const LOAD_ERROR:       &str = "load_error";
+const BUTTON_RETRY:     &str = "button_load_retry";
+const BUTTON_QUIT:      &str = "button_load_quit";
+const CURSOR:           &str = "load_error_cursor";
+const ERROR_LIST:       &str = "load_error_list";
+const MAX_SHOWN_ERRORS: usize = 8;     // the other errors are only counted, the log has them all
+
+//=========================
+// Define load error state
+//=========================
+//
+// Switched to by the loading state when assets fail to load, retry starts the loading over
+#[derive(Default)]
+pub struct LoadErrorState {
+    errors:                 Vec<String>,
+    load_error_screen:      Option<Entity>,
+    load_error_cursor:      Option<Entity>,
+    load_error_is_ready:    bool,
+}
+
+impl LoadErrorState {
+    pub fn new(errors: Vec<String>) -> Self {
+        LoadErrorState {
+            errors,
+            ..LoadErrorState::default()
+        }
+    }
+}
+
+impl SimpleState for LoadErrorState {
+    fn on_start(&mut self, data: StateData<GameData>) {
+        for error in self.errors.iter() {
+            error!("Failed to load {}", error);
+        }
+        let load_error_prefab = data
+            .world
+            .read_resource::<UiPrefabRegistry>()
+            .find(data.world, LOAD_ERROR);
+        if let Some(load_error_prefab) = load_error_prefab {
+            self.load_error_screen = Some(data
+                .world
+                .create_entity()
+                .with(load_error_prefab)
+                .build()
+            );
+        }
+    }
+
+    fn on_stop(&mut self, data: StateData<GameData>) {
+        self.load_error_is_ready = false;
+        if let Some(load_error_screen) = self.load_error_screen {
+            if data.world.delete_entity(load_error_screen).is_ok() {
+                self.load_error_cursor = None;
+                self.load_error_screen = None;
+            }
+        }
+    }
+
+    fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        data.data.update(&data.world);
+
+        // nothing can be shown without the screen, the errors are in the log
+        if self.load_error_screen.is_none() {
+            return Trans::Quit;
+        }
+
+        if !self.load_error_is_ready {
+            let (cursor, error_list) = data.world.exec(|ui_finder: UiFinder<'_>| {
+                (ui_finder.find(CURSOR), ui_finder.find(ERROR_LIST))
+            });
+            self.load_error_cursor   = cursor;
+            self.load_error_is_ready = cursor.is_some();
+            if let Some(error_list) = error_list {
+                let text = error_list_text(&self.errors);
+                set_text(&error_list, data, &text);
+            }
+        }
+        Trans::None
+    }
+
+    fn handle_event(&mut self, mut data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+        if !self.load_error_is_ready {
+            return Trans::None;
+        }
+        match event {
+            StateEvent::Input(InputEvent::ActionPressed(action)) => {
+                if action == "back" {
+                    return Trans::Quit;
+                } else if action == "confirm" {
+                    return self.confirm_option(&mut data);
+                } else if action == "ui_up" {
+                    if let Some(cursor) = self.load_error_cursor {
+                        move_cursor(&cursor, &mut data, false);
+                    }
+                } else if action == "ui_down" {
+                    if let Some(cursor) = self.load_error_cursor {
+                        move_cursor(&cursor, &mut data, true);
+                    }
+                }
+                Trans::None
+            },
+            StateEvent::Ui(ui_event) => {
+                if let Some(cursor) = self.load_error_cursor {
+                    if cursor_pointer_event(&cursor, &ui_event, &mut data) {
+                        return self.confirm_option(&mut data);
+                    }
+                }
+                Trans::None
+            },
+            _ => Trans::None,
+        }
+    }
+}
+
+impl LoadErrorState {
+    fn confirm_option(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        play_sfx_named(data.world, SFX_BUTTON_PUSH);
+        if let Some(cursor) = self.load_error_cursor {
+            match get_cursor_action(&cursor, data).as_str() {
+                BUTTON_RETRY => return Trans::Switch(Box::new(LoadingState::default())),
+                BUTTON_QUIT  => return Trans::Quit,
+                _ => {}
+            }
+        }
+        Trans::None
+    }
+}
+
+// one line per failed asset
+fn error_list_text(errors: &[String]) -> String {
+    let mut lines = errors
+        .iter()
+        .take(MAX_SHOWN_ERRORS)
+        .cloned()
+        .collect::<Vec<_>>();
+    if errors.len() > MAX_SHOWN_ERRORS {
+        lines.push(format!("AND {} MORE", errors.len() - MAX_SHOWN_ERRORS));
+    }
+    lines.join("\n")
+}
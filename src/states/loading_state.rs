@@ -3,35 +3,43 @@ use std::fs::read_dir;
 
 // amethyst modules
 use amethyst::{
+    config::Config,
     prelude::*,
     ecs::Entity,
     assets::{
         Completion, 
         ProgressCounter,
         Handle,
-        // PrefabLoader,
-        // RonFormat,
-    },
-    ui::{
-        UiLoader,
-        UiPrefab,
-        UiFinder,
+        Prefab,
+        PrefabLoader,
+        RonFormat,
     },
+    ui::UiLoader,
     utils::application_root_dir,
 };
 
 // local modules
-use crate::components::ui_glowing_comp::UiGlowingStyle;
 use crate::states::disclaimer_state::DisclaimerState;
+use crate::states::load_error_state::LoadErrorState;
 use crate::resources::ui_prefab_registry::UiPrefabRegistry;
-use crate::resources::ui_helper::impl_glowing_comp;
+use crate::resources::paddle_prefab_registry::PaddlePrefabRegistry;
+use crate::resources::match_setup::MatchSetup;
+use crate::resources::score::{Score, MatchResult, ScoreHud};
+use crate::states::pause_state::PauseAction;
+use crate::resources::ai_profiles::AiProfiles;
+use crate::resources::stage_registry::{StageRegistry, StageDef};
+use crate::components::paddle_prefab::PaddlePrefabData;
+use crate::components::ui_effects_prefab::{PsUi, PsUiPrefab};
 use crate::resources::audio::initialize_audio;
+use crate::resources::settings::{Settings, apply_fullscreen};
+use crate::resources::loading_progress::LoadingProgress;
+use crate::resources::timeline::load_timelines;
+use crate::resources::screen_fade::{init_screen_fade, load_fade_transitions, switch_with_fade};
 
 //===========
 // Constants
 //===========
 const LOADING_SCREEN_ID: &str = "loading_screen";
-const LOADING_TEXT_ID:   &str = "loading_label";
 
 //=======================
 // Declare loading state
@@ -45,9 +53,9 @@ pub struct LoadingState {
     // Tracks loaded assets.
     loading_screen_progress:    Option<ProgressCounter>,
     loading_prefabs_progress:   Option<ProgressCounter>,
+    loading_audio_progress:     Option<ProgressCounter>,
     loading_screen:             Option<Entity>,
-    loading_screen_text:        Option<Entity>,
-    loading_screen_is_ready:    bool,
+    load_errors:                Vec<String>,    // failures found before the loader started
 }
 
 //=======================
@@ -59,11 +67,35 @@ impl SimpleState for LoadingState {
     // Start up tasks
     //----------------
     fn on_start(&mut self, mut data: StateData<GameData>) {
-        initialize_audio(data.world);
-        let mut ui_prefab_registry = UiPrefabRegistry::default();
+        let fullscreen = data.world.read_resource::<Settings>().fullscreen;
+        apply_fullscreen(data.world, fullscreen);
+        let mut ui_prefab_registry      = UiPrefabRegistry::default();
+        let mut paddle_prefab_registry  = PaddlePrefabRegistry::default();
         self.loading_screen_progress    = Some(load_loading_screen(&mut data.world, &mut ui_prefab_registry));
-        self.loading_prefabs_progress   = Some(load_prefabs(&mut data.world, &mut ui_prefab_registry));
+        match load_prefabs(&mut data.world, &mut ui_prefab_registry, &mut paddle_prefab_registry) {
+            Ok(progress_counter) => self.loading_prefabs_progress = Some(progress_counter),
+            Err(load_error) => self.load_errors.push(load_error),
+        }
+        self.loading_audio_progress     = Some(load_audio(&mut data.world));
         data.world.insert(ui_prefab_registry);
+        data.world.insert(paddle_prefab_registry);
+        data.world.insert(MatchSetup::default());
+        data.world.insert(Score::default());
+        data.world.insert(ScoreHud::default());
+        data.world.insert(MatchResult::default());
+        data.world.insert(PauseAction::default());
+        data.world.insert(load_ai_profiles());
+        match load_stages() {
+            Ok(stage_registry) => data.world.insert(stage_registry),
+            Err(load_error) => {
+                self.load_errors.push(load_error);
+                data.world.insert(StageRegistry::default());
+            }
+        }
+        data.world.insert(load_timelines());
+        data.world.insert(load_fade_transitions());
+        init_screen_fade(data.world);
+        data.world.insert(LoadingProgress::default());
     }
 
     //----------------
@@ -71,14 +103,14 @@ impl SimpleState for LoadingState {
     //----------------
     fn on_stop(&mut self, data: StateData<GameData>) {
         // clean up
-        self.loading_screen_is_ready    = false;
         self.loading_screen_progress    = None;
         self.loading_prefabs_progress   = None;
+        self.loading_audio_progress     = None;
+        self.load_errors.clear();
         // remove loading screen
         if let Some(loading_screen) = self.loading_screen {
             if data.world.delete_entity(loading_screen).is_ok() {
-                self.loading_screen_text = None;
-                self.loading_screen      = None;
+                self.loading_screen = None;
             }
         }
 
@@ -91,6 +123,7 @@ impl SimpleState for LoadingState {
 
         // update game data
         data.data.update(&data.world);
+        self.update_loading_progress(data.world);
 
         // show loading screen when completed
         if self.loading_screen.is_none() {
@@ -118,23 +151,25 @@ impl SimpleState for LoadingState {
                     }
                 }           
             }
-        } else if !self.loading_screen_is_ready {
-            self.loading_screen_text = data.world.exec(|ui_finder: UiFinder<'_>| {
-                ui_finder.find(LOADING_TEXT_ID) 
-            });
-            if let Some(loading_text) = self.loading_screen_text {   
-                impl_glowing_comp(
-                    &loading_text, 
-                    data, 
-                    true, 
-                    1., 
-                    0.8, 
-                    UiGlowingStyle::Darkening, 
-                    [1., 1., 0., 0.]
-                );
+        }
+
+        // the load error screen comes with the loading screen, it can be shown from here
+        if !self.load_errors.is_empty() {
+            return Trans::Switch(Box::new(LoadErrorState::new(self.load_errors.clone())));
+        }
+
+        // the audio is waited for as well, the next states play sounds right away
+        if let Some(ref load_audio_prog) = self.loading_audio_progress.as_ref() {
+            match load_audio_prog.complete() {
+                Completion::Loading  => {
+                    return Trans::None;
+                }
+                Completion::Failed   => {
+                    return Trans::Switch(Box::new(LoadErrorState::new(failed_assets(load_audio_prog))));
+                }
+                Completion::Complete => {}
             }
-            self.loading_screen_is_ready = true;
-        } 
+        }
 
         if let Some(ref load_prefabs_prog) = self.loading_prefabs_progress.as_ref() {
             match load_prefabs_prog.complete() {
@@ -142,11 +177,10 @@ impl SimpleState for LoadingState {
                     return Trans::None;
                 }
                 Completion::Failed   => {
-                    error!("Prefabs Failed to Load!");
-                    return Trans::Quit;
+                    return Trans::Switch(Box::new(LoadErrorState::new(failed_assets(load_prefabs_prog))));
                 }
                 Completion::Complete => {
-                    return Trans::Switch(Box::new(DisclaimerState::default()));
+                    return switch_with_fade(data.world, "to_disclaimer", Box::new(DisclaimerState::default()));
                 }
             }
         } 
@@ -155,27 +189,56 @@ impl SimpleState for LoadingState {
     }
 }
 
-// Load Loading screen in another thread, and register it
+impl LoadingState {
+    // the loading screen itself is left out, the bar is only visible once it is loaded
+    fn update_loading_progress(&self, world: &mut World) {
+        let mut loading_progress = LoadingProgress::default();
+        let counters = [&self.loading_prefabs_progress, &self.loading_audio_progress];
+        for progress_counter in counters.iter().filter_map(|counter| counter.as_ref()) {
+            loading_progress.finished += progress_counter.num_finished();
+            loading_progress.total    += progress_counter.num_assets();
+        }
+        world.insert(loading_progress);
+    }
+}
+
+// Load Loading screen and load error screen in another thread, and register them
 fn load_loading_screen(world: &mut World, registry:&mut UiPrefabRegistry) -> ProgressCounter {
     let mut progress_counter = ProgressCounter::new();
-    let laoding_screen_path = application_root_dir()
+    let ui_loading_dir_path = application_root_dir()
         .unwrap()
         .into_os_string()
         .into_string()
         .unwrap()
-        + "/resources/prefabs/ui_loading/loading_screen.ron";
-
-    registry.prefabs.push(world.exec(|loader: UiLoader<'_>| {
-        loader.load(
-            laoding_screen_path,
-            &mut progress_counter,
-        )
-    }));
+        + "/resources/prefabs/ui_loading";
+
+    for file_name in ["loading_screen.ron", "load_error.ron"].iter() {
+        registry.prefabs.push(world.exec(|loader: UiLoader<'_, PsUi>| {
+            loader.load(
+                format!("{}/{}", ui_loading_dir_path, file_name),
+                &mut progress_counter,
+            )
+        }));
+    }
     progress_counter  
 }
 
-// Load Prefabs in another thread, and register it
-fn load_prefabs(world: &mut World, registry:&mut UiPrefabRegistry) -> ProgressCounter {
+// Name and reason of every asset that failed to load
+fn failed_assets(progress_counter: &ProgressCounter) -> Vec<String> {
+    progress_counter
+        .errors()
+        .iter()
+        .map(|asset_error| format!("{}: {}", asset_error.asset_name, asset_error.error))
+        .collect()
+}
+
+// Load Prefabs in another thread, and register it.
+// An unreadable prefab directory stops the loading before anything is requested.
+fn load_prefabs(
+    world:              &mut World,
+    registry:           &mut UiPrefabRegistry,
+    paddle_registry:    &mut PaddlePrefabRegistry,
+) -> Result<ProgressCounter, String> {
     let mut progress_counter = ProgressCounter::new();
 
     // UI Prefabs
@@ -185,43 +248,113 @@ fn load_prefabs(world: &mut World, registry:&mut UiPrefabRegistry) -> ProgressCo
         .into_string()
         .unwrap()
         + "/resources/prefabs/ui";
-    let ui_prefab_iter = read_dir(ui_prefab_dir_path).unwrap();
-    registry.prefabs.extend(ui_prefab_iter
-        .map(|prefab_dir_entry| {
-            world.exec(|loader: UiLoader<'_>| {
+    let ui_prefab_names = prefab_names("prefabs/ui/", &ui_prefab_dir_path)?;
+    registry.prefabs.extend(ui_prefab_names
+        .into_iter()
+        .map(|prefab_name| {
+            world.exec(|loader: UiLoader<'_, PsUi>| {
                 loader.load(
-                    make_name("prefabs/ui/", &prefab_dir_entry.unwrap()),
+                    prefab_name,
                     &mut progress_counter,
                 )
             })
         })
-        .collect::<Vec<Handle<UiPrefab>>>());
+        .collect::<Vec<Handle<PsUiPrefab>>>());
     
     // Paddle Prefabs
-    // let game_prefab_iter = {
-    //     let game_prefab_dir_path = application_root_dir()
-    //         .unwrap()
-    //         .into_os_string()
-    //         .into_string()
-    //         .unwrap()
-    //         + "/resources/prefabs/paddles";
-    //     let game_prefab_iter_temp = read_dir(game_prefab_dir_path).unwrap();
-    //     game_prefab_iter_temp.map(|game_prefab_dir_entry|{
-    //         world.exec(|loader: PrefabLoader<'_, PaddlePrefabData>| {
-    //             loader.load(
-    //                 make_name("prefabs/paddles/", &game_prefab_dir_entry.unwrap()),
-    //                 RonFormat,
-    //                 &mut progress_counter,
-    //             )
-    //         })
-    //     })
-    // };
+    let paddle_prefab_dir_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/prefabs/paddles";
+    let paddle_prefab_names = prefab_names("prefabs/paddles/", &paddle_prefab_dir_path)?;
+    paddle_registry.prefabs.extend(paddle_prefab_names
+        .into_iter()
+        .map(|prefab_name| {
+            world.exec(|loader: PrefabLoader<'_, PaddlePrefabData>| {
+                loader.load(
+                    prefab_name,
+                    RonFormat,
+                    &mut progress_counter,
+                )
+            })
+        })
+        .collect::<Vec<Handle<Prefab<PaddlePrefabData>>>>());
+
+    Ok(progress_counter)
+}
 
+// Asset names of the prefab files of a directory
+fn prefab_names(subdirectory: &str, dir_path: &str) -> Result<Vec<String>, String> {
+    let prefab_dir_iter = read_dir(dir_path)
+        .map_err(|error| format!("{}: {}", dir_path, error))?;
+    prefab_dir_iter
+        .map(|prefab_dir_entry| {
+            let prefab_dir_entry = prefab_dir_entry
+                .map_err(|error| format!("{}: {}", dir_path, error))?;
+            make_name(subdirectory, &prefab_dir_entry)
+        })
+        .collect()
+}
+
+// Load the menu music and the sound effects in another thread
+fn load_audio(world: &mut World) -> ProgressCounter {
+    let mut progress_counter = ProgressCounter::new();
+    initialize_audio(world, &mut progress_counter);
     progress_counter
 }
 
-fn make_name(subdirectory: &str, entry: &std::fs::DirEntry) -> String {
+// Load CPU difficulty profiles
+fn load_ai_profiles() -> AiProfiles {
+    let ai_profiles_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/ai_profiles.ron";
+    AiProfiles::load(ai_profiles_path)
+}
+
+// Load arcade stage definitions from the ron files, sorted by file name.
+// An unreadable stage directory is shown on the load error screen,
+// a stage file that does not parse is left out with a warning.
+fn load_stages() -> Result<StageRegistry, String> {
+    let stage_dir_path = application_root_dir()
+        .unwrap()
+        .into_os_string()
+        .into_string()
+        .unwrap()
+        + "/resources/stages";
+    let mut stage_paths = read_dir(&stage_dir_path)
+        .map_err(|error| format!("{}: {}", stage_dir_path, error))?
+        .map(|stage_dir_entry| {
+            stage_dir_entry
+                .map(|stage_dir_entry| stage_dir_entry.path())
+                .map_err(|error| format!("{}: {}", stage_dir_path, error))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    stage_paths.retain(|stage_path| stage_path.extension().map_or(false, |extension| extension == "ron"));
+    stage_paths.sort();
+    Ok(StageRegistry {
+        stages: stage_paths
+            .iter()
+            .filter_map(|stage_path| match StageDef::load_no_fallback(stage_path) {
+                Ok(stage) => Some(stage),
+                Err(error) => {
+                    warn!("Stage {} is skipped: {}", stage_path.display(), error);
+                    None
+                }
+            })
+            .collect(),
+    })
+}
+
+fn make_name(subdirectory: &str, entry: &std::fs::DirEntry) -> Result<String, String> {
     let path_buffer = entry.path();
-    let filename = path_buffer.file_name().unwrap();
-    format!("{}{}", subdirectory, filename.to_str().unwrap())
+    let filename = path_buffer
+        .file_name()
+        .and_then(|filename| filename.to_str())
+        .ok_or_else(|| format!("{}: invalid file name", path_buffer.display()))?;
+    Ok(format!("{}{}", subdirectory, filename))
 }
\ No newline at end of file
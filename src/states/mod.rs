@@ -38,3 +38,10 @@ pub mod loading_state;
 pub mod disclaimer_state;
 pub mod main_menu_state;
 pub mod arcade_game_state;
+
+pub mod results_state;
+pub mod pause_state;
+pub mod controls_state;
+pub mod options_state;
+pub mod load_error_state;
+pub mod screen_fade_state;
\ No newline at end of file
@@ -0,0 +1,294 @@
+// amethyst modules
+use amethyst::{
+    ecs::Entity,
+    prelude::*,
+    input::InputEvent,
+    ui::UiFinder,
+};
+
+// local modules
+use crate::states::controls_state::ControlsState;
+use crate::resources::ui_prefab_registry::UiPrefabRegistry;
+use crate::resources::ui_helper::*;
+use crate::resources::audio::{SFX_BUTTON_PUSH, SFX_CURSOR_TICK, play_sfx_named, set_music_volume};
+use crate::resources::settings::{Settings, apply_fullscreen};
+
+//===========
+// Constants
+//===========
+const OPTIONS:          &str = "options";
+const BUTTON_CONTROLS:  &str = "button_options_controls";
+const BUTTON_BACK:      &str = "button_options_back";
+const CURSOR:           &str = "options_cursor";
+const MESSAGE:          &str = "options_message";
+const VALUE_SUFFIX:     &str = "_value";
+const HELP_MESSAGE:     &str = "LEFT AND RIGHT TO CHANGE A VALUE";
+const VSYNC_MESSAGE:    &str = "VSYNC IS APPLIED AFTER A RESTART";
+
+const OPTION_MUSIC_VOLUME:  &str = "option_music_volume";
+const OPTION_SFX_VOLUME:    &str = "option_sfx_volume";
+const OPTION_FULLSCREEN:    &str = "option_fullscreen";
+const OPTION_VSYNC:         &str = "option_vsync";
+const OPTION_CPU_LEVEL:     &str = "option_cpu_level";
+const OPTION_WIN_SCORE:     &str = "option_win_score";
+const OPTION_TIME_LIMIT:    &str = "option_time_limit";
+
+// the options with a value label, in cursor order
+const VALUE_OPTIONS: [&str; 7] = [
+    OPTION_MUSIC_VOLUME,
+    OPTION_SFX_VOLUME,
+    OPTION_FULLSCREEN,
+    OPTION_VSYNC,
+    OPTION_CPU_LEVEL,
+    OPTION_WIN_SCORE,
+    OPTION_TIME_LIMIT,
+];
+
+const VOLUME_STEP:  f32 = 0.1;
+const CPU_LEVELS:   [&str; 4] = ["easy", "normal", "hard", "master"];
+const WIN_SCORES:   [u32; 6]  = [3, 5, 7, 11, 15, 0];
+const TIME_LIMITS:  [f32; 5]  = [60., 120., 180., 300., 0.];
+
+//======================
+// Define options state
+//======================
+//
+// Pushed over the main menu, every change is applied and saved right away
+#[derive(Default)]
+pub struct OptionsState {
+    options_screen:         Option<Entity>,
+    options_cursor:         Option<Entity>,
+    options_message:        Option<Entity>,
+    value_labels:           Vec<Option<Entity>>,
+    options_is_ready:       bool,
+}
+
+impl SimpleState for OptionsState {
+    fn on_start(&mut self, data: StateData<GameData>) {
+        let options_prefab = data
+            .world
+            .read_resource::<UiPrefabRegistry>()
+            .find(data.world, OPTIONS);
+        if let Some(options_prefab) = options_prefab {
+            self.options_screen = Some(data
+                .world
+                .create_entity()
+                .with(options_prefab)
+                .build()
+            );
+        }
+    }
+
+    fn on_stop(&mut self, data: StateData<GameData>) {
+        self.options_is_ready = false;
+        if let Some(options_screen) = self.options_screen {
+            if data.world.delete_entity(options_screen).is_ok() {
+                self.value_labels.clear();
+                self.options_cursor  = None;
+                self.options_message = None;
+                self.options_screen  = None;
+            }
+        }
+    }
+
+    fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        data.data.update(&data.world);
+
+        if !self.options_is_ready && self.options_screen.is_some() {
+            let (cursor, message, value_labels) = data.world.exec(|ui_finder: UiFinder<'_>| {
+                (
+                    ui_finder.find(CURSOR),
+                    ui_finder.find(MESSAGE),
+                    VALUE_OPTIONS
+                        .iter()
+                        .map(|option| ui_finder.find(&format!("{}{}", option, VALUE_SUFFIX)))
+                        .collect::<Vec<_>>(),
+                )
+            });
+            self.options_cursor     = cursor;
+            self.options_message    = message;
+            self.value_labels       = value_labels;
+            self.options_is_ready   = cursor.is_some();
+            if self.options_is_ready {
+                self.refresh_value_labels(data);
+                self.show_message(data, HELP_MESSAGE);
+            }
+        }
+        Trans::None
+    }
+
+    fn handle_event(&mut self, mut data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+        if !self.options_is_ready {
+            return Trans::None;
+        }
+        match event {
+            StateEvent::Input(InputEvent::ActionPressed(action)) => {
+                if action == "back" || action == "pause" {
+                    return Trans::Pop;
+                } else if action == "confirm" {
+                    return self.confirm_option(&mut data);
+                } else if action == "ui_up" {
+                    if let Some(cursor) = self.options_cursor {
+                        move_cursor(&cursor, &mut data, false);
+                    }
+                } else if action == "ui_down" {
+                    if let Some(cursor) = self.options_cursor {
+                        move_cursor(&cursor, &mut data, true);
+                    }
+                }
+                Trans::None
+            },
+            // the paddle keys change the pointed value
+            StateEvent::Input(InputEvent::AxisMoved { axis, value }) => {
+                if (axis == "p1_move" || axis == "p2_move") && value != 0. {
+                    if let Some(cursor) = self.options_cursor {
+                        let option = get_cursor_action(&cursor, &mut data);
+                        self.change_option(&mut data, &option, value > 0., false);
+                    }
+                }
+                Trans::None
+            },
+            StateEvent::Ui(ui_event) => {
+                if let Some(cursor) = self.options_cursor {
+                    if cursor_pointer_event(&cursor, &ui_event, &mut data) {
+                        return self.confirm_option(&mut data);
+                    }
+                }
+                Trans::None
+            },
+            _ => Trans::None,
+        }
+    }
+}
+
+impl OptionsState {
+    // the buttons open their screen, the values go one step forward
+    fn confirm_option(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        play_sfx_named(data.world, SFX_BUTTON_PUSH);
+        if let Some(cursor) = self.options_cursor {
+            let option = get_cursor_action(&cursor, data);
+            match option.as_str() {
+                BUTTON_CONTROLS => return Trans::Push(Box::new(ControlsState::default())),
+                BUTTON_BACK     => return Trans::Pop,
+                _               => self.change_option(data, &option, true, true),
+            }
+        }
+        Trans::None
+    }
+
+    fn change_option(&mut self, data: &mut StateData<GameData>, option: &str, forward: bool, wrap: bool) {
+        if !VALUE_OPTIONS.contains(&option) {
+            return;
+        }
+        let settings = {
+            let mut settings = data.world.write_resource::<Settings>();
+            match option {
+                OPTION_MUSIC_VOLUME => settings.music_volume = step_volume(settings.music_volume, forward, wrap),
+                OPTION_SFX_VOLUME   => settings.sfx_volume = step_volume(settings.sfx_volume, forward, wrap),
+                OPTION_FULLSCREEN   => settings.fullscreen = !settings.fullscreen,
+                OPTION_VSYNC        => settings.vsync = !settings.vsync,
+                OPTION_CPU_LEVEL    => {
+                    settings.ai_profile = step_choice(&CPU_LEVELS, &settings.ai_profile.as_str(), forward).to_string();
+                }
+                OPTION_WIN_SCORE    => settings.win_score = step_choice(&WIN_SCORES, &settings.win_score, forward),
+                OPTION_TIME_LIMIT   => settings.time_limit = step_choice(&TIME_LIMITS, &settings.time_limit, forward),
+                _ => {}
+            }
+            settings.clone()
+        };
+
+        // apply the changes that do not wait for the next match
+        match option {
+            OPTION_MUSIC_VOLUME => set_music_volume(data.world, settings.music_volume),
+            OPTION_SFX_VOLUME   => play_sfx_named(data.world, SFX_CURSOR_TICK),
+            OPTION_FULLSCREEN   => apply_fullscreen(data.world, settings.fullscreen),
+            _ => {}
+        }
+
+        let message = if let Err(error) = settings.save() {
+            warn!("Failed to save the settings: {}", error);
+            "THE SETTINGS COULD NOT BE SAVED"
+        } else if option == OPTION_VSYNC {
+            VSYNC_MESSAGE
+        } else {
+            HELP_MESSAGE
+        };
+        self.refresh_value_labels(data);
+        self.show_message(data, message);
+    }
+
+    fn refresh_value_labels(&self, data: &mut StateData<GameData>) {
+        let values = {
+            let settings = data.world.read_resource::<Settings>();
+            VALUE_OPTIONS
+                .iter()
+                .map(|option| option_text(&settings, option))
+                .collect::<Vec<_>>()
+        };
+        for (value_label, value) in self.value_labels.iter().zip(values.iter()) {
+            if let Some(value_label) = value_label {
+                set_text(value_label, data, value);
+            }
+        }
+    }
+
+    fn show_message(&self, data: &mut StateData<GameData>, message: &str) {
+        if let Some(options_message) = self.options_message {
+            set_text(&options_message, data, message);
+        }
+    }
+}
+
+// volumes go from 0 to 1, confirm wraps around to silence while the arrows stop at the ends
+fn step_volume(volume: f32, forward: bool, wrap: bool) -> f32 {
+    let level = (volume / VOLUME_STEP).round();
+    let max_level = (1. / VOLUME_STEP).round();
+    let level = if forward {
+        if level < max_level { level + 1. } else if wrap { 0. } else { max_level }
+    } else {
+        (level - 1.).max(0.)
+    };
+    level * VOLUME_STEP
+}
+
+// next or previous choice, an unknown value restarts from the first choice
+fn step_choice<T: PartialEq + Clone>(choices: &[T], current: &T, forward: bool) -> T {
+    let index = match choices.iter().position(|choice| choice == current) {
+        Some(index) => {
+            if forward {
+                (index + 1) % choices.len()
+            } else {
+                (index + choices.len() - 1) % choices.len()
+            }
+        }
+        None => 0,
+    };
+    choices[index].clone()
+}
+
+fn option_text(settings: &Settings, option: &str) -> String {
+    let on_off = |on: bool| if on { "ON".to_string() } else { "OFF".to_string() };
+    match option {
+        OPTION_MUSIC_VOLUME => format!("{}", (settings.music_volume * 100.).round()),
+        OPTION_SFX_VOLUME   => format!("{}", (settings.sfx_volume * 100.).round()),
+        OPTION_FULLSCREEN   => on_off(settings.fullscreen),
+        OPTION_VSYNC        => on_off(settings.vsync),
+        OPTION_CPU_LEVEL    => settings.ai_profile.to_uppercase(),
+        OPTION_WIN_SCORE    => {
+            if settings.win_score == 0 {
+                "NO LIMIT".to_string()
+            } else {
+                format!("{}", settings.win_score)
+            }
+        }
+        OPTION_TIME_LIMIT   => {
+            if settings.time_limit <= 0. {
+                "NO LIMIT".to_string()
+            } else {
+                let seconds = settings.time_limit.round() as u32;
+                format!("{}:{:02}", seconds / 60, seconds % 60)
+            }
+        }
+        _ => "".to_string(),
+    }
+}
@@ -0,0 +1,143 @@
+// amethyst modules
+use amethyst::{
+    ecs::Entity,
+    prelude::*,
+    input::InputEvent,
+    ui::UiFinder,
+};
+
+// local modules
+use crate::resources::ui_prefab_registry::UiPrefabRegistry;
+use crate::resources::ui_helper::*;
+use crate::resources::audio::{SFX_BUTTON_PUSH, play_sfx_named};
+
+//===========
+// Constants
+//===========
+const PAUSE_MENU:           &str = "pause_menu";
+const BUTTON_RESTART:       &str = "button_restart";
+const BUTTON_QUIT_TO_MENU:  &str = "button_quit_to_menu";
+const CURSOR:               &str = "pause_cursor";
+
+//==============
+// Pause Action
+//==============
+//
+// Left in the world by the pause state, the game state below acts on it after the pop
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PauseAction {
+    Resume,
+    Restart,
+    QuitToMenu,
+}
+
+impl Default for PauseAction {
+    fn default() -> Self {
+        PauseAction::Resume
+    }
+}
+
+//====================
+// Define pause state
+//====================
+//
+// Pushed over the game state, which stays on the stack while the menu is open
+#[derive(Default)]
+pub struct PauseState {
+    pause_menu_screen:      Option<Entity>,
+    pause_menu_cursor:      Option<Entity>,
+    pause_menu_is_ready:    bool,
+}
+
+impl SimpleState for PauseState {
+    fn on_start(&mut self, data: StateData<GameData>) {
+        data.world.insert(PauseAction::Resume);
+        let pause_menu_prefab = data
+            .world
+            .read_resource::<UiPrefabRegistry>()
+            .find(data.world, PAUSE_MENU);
+        if let Some(pause_menu_prefab) = pause_menu_prefab {
+            self.pause_menu_screen = Some(data
+                .world
+                .create_entity()
+                .with(pause_menu_prefab)
+                .build()
+            );
+        }
+    }
+
+    fn on_stop(&mut self, data: StateData<GameData>) {
+        self.pause_menu_is_ready = false;
+        if let Some(pause_menu_screen) = self.pause_menu_screen {
+            if data.world.delete_entity(pause_menu_screen).is_ok() {
+                self.pause_menu_cursor = None;
+                self.pause_menu_screen = None;
+            }
+        }
+    }
+
+    fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        data.data.update(&data.world);
+
+        // the effects of the menu items are declared in pause_menu.ron
+        if !self.pause_menu_is_ready && self.pause_menu_screen.is_some() {
+            self.pause_menu_cursor = data.world.exec(|ui_finder: UiFinder<'_>| {
+                ui_finder.find(CURSOR)
+            });
+            self.pause_menu_is_ready = self.pause_menu_cursor.is_some();
+        }
+        Trans::None
+    }
+
+    fn handle_event(&mut self, mut data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+        match event {
+            StateEvent::Input(input_event) => {
+                if let InputEvent::ActionPressed(action) = input_event {
+                    if action == "pause" || action == "back" {
+                        // the pause and back keys close the menu again
+                        return Trans::Pop;
+                    } else if action == "confirm" {
+                        return self.confirm_option(&mut data);
+                    } else if action == "ui_up" {
+                        if let Some(cursor) = self.pause_menu_cursor {
+                            move_cursor(&cursor, &mut data, false);
+                        }
+                    } else if action == "ui_down" {
+                        if let Some(cursor) = self.pause_menu_cursor {
+                            move_cursor(&cursor, &mut data, true);
+                        }
+                    }
+                    Trans::None
+                } else {
+                    Trans::None
+                }
+            },
+            StateEvent::Ui(ui_event) => {
+                if let Some(cursor) = self.pause_menu_cursor {
+                    if cursor_pointer_event(&cursor, &ui_event, &mut data) {
+                        return self.confirm_option(&mut data);
+                    }
+                }
+                Trans::None
+            },
+            _ => Trans::None,
+        }
+    }
+}
+
+impl PauseState {
+    // leave the pause menu with the action pointed by the cursor
+    fn confirm_option(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        play_sfx_named(data.world, SFX_BUTTON_PUSH);
+        if let Some(cursor) = self.pause_menu_cursor {
+            let pause_action = match get_cursor_action(&cursor, data).as_str() {
+                BUTTON_RESTART      => PauseAction::Restart,
+                BUTTON_QUIT_TO_MENU => PauseAction::QuitToMenu,
+                _                   => PauseAction::Resume,
+            };
+            data.world.insert(pause_action);
+            return Trans::Pop;
+        }
+        Trans::None
+    }
+}
@@ -0,0 +1,116 @@
+//=============
+// Importation
+//=============
+
+// amethyst modules
+use amethyst::{
+    ecs::Entity,
+    prelude::*,
+    input::InputEvent,
+    ui::UiFinder,
+};
+
+// local modules
+use crate::states::main_menu_state::MainMenuState;
+use crate::resources::ui_prefab_registry::UiPrefabRegistry;
+use crate::resources::ui_helper::{
+    impl_flashing_comp,
+    set_text,
+};
+use crate::resources::score::MatchResult;
+use crate::resources::audio::{switch_playlist, RESULTS_PLAYLIST, MUSIC_CROSSFADE};
+use crate::resources::screen_fade::switch_with_fade;
+
+//===========
+// Constants
+//===========
+const RESULTS_ID:   &str = "results";
+const TITLE:        &str = "results_title";
+const WINNER:       &str = "results_winner";
+const SCORE:        &str = "results_score";
+
+//======================
+// Define results state
+//======================
+#[derive(Default)]
+pub struct ResultsState {
+    results_screen:     Option<Entity>,
+    results_is_ready:   bool,
+}
+
+impl SimpleState for ResultsState {
+    fn on_start(&mut self, data: StateData<GameData>) {
+        let results_prefab = data
+            .world
+            .read_resource::<UiPrefabRegistry>()
+            .find(data.world, RESULTS_ID);
+        if let Some(results_prefab) = results_prefab {
+            self.results_screen = Some(data
+                .world
+                .create_entity()
+                .with(results_prefab)
+                .build()
+            );
+        }
+        switch_playlist(data.world, RESULTS_PLAYLIST, Some(MUSIC_CROSSFADE));
+    }
+
+    fn on_stop(&mut self, data: StateData<GameData>) {
+        self.results_is_ready = false;
+        if let Some(results_screen) = self.results_screen {
+            if data.world.delete_entity(results_screen).is_ok() {
+                self.results_screen = None;
+            }
+        }
+    }
+
+    fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        data.data.update(&data.world);
+        if !self.results_is_ready && self.results_screen.is_some() {
+            let (title, winner, score) = data.world.exec(|ui_finder: UiFinder<'_>| {
+                (
+                    ui_finder.find(TITLE),
+                    ui_finder.find(WINNER),
+                    ui_finder.find(SCORE),
+                )
+            });
+            // the labels are not created yet
+            if title.is_none() {
+                return Trans::None;
+            }
+
+            let result = data.world.read_resource::<MatchResult>().clone();
+            if let Some(title) = title {
+                set_text(&title, data, &result.title);
+            }
+            if let Some(score) = score {
+                set_text(&score, data, &format!("{} - {}", result.points[0], result.points[1]));
+            }
+            if let Some(winner) = winner {
+                set_text(&winner, data, &result.winner_text);
+                // a draw has no winner to highlight
+                if result.winner.is_some() {
+                    impl_flashing_comp(&winner, data, [1., 1., 0., 1.], true, 0.2);
+                }
+            }
+            self.results_is_ready = true;
+        }
+        Trans::None
+    }
+
+    fn handle_event(&mut self, data: StateData<GameData>, event: StateEvent) -> SimpleTrans {
+        match event {
+            StateEvent::Input(input_event) => {
+                if let InputEvent::ActionPressed(action) = input_event {
+                    if (action == "confirm" || action == "back") && self.results_is_ready {
+                        return switch_with_fade(data.world, "to_main_menu", Box::new(MainMenuState::default()));
+                    }
+                    Trans::None
+                } else {
+                    Trans::None
+                }
+            },
+            _ => Trans::None,
+        }
+    }
+}
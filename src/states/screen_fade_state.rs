@@ -0,0 +1,65 @@
+// amethyst modules
+use amethyst::prelude::*;
+
+// local modules
+use crate::resources::screen_fade::{FadeTransition, ScreenFade};
+
+//==========================
+// Define screen fade state
+//==========================
+//
+// Pushed over the old state to cover it, then over the next state to uncover it.
+// Being on top, it takes every input event while the screen fades.
+pub struct ScreenFadeState {
+    transition: FadeTransition,
+    next_state: Option<Box<dyn State<GameData<'static, 'static>, StateEvent>>>,  // none while fading in
+}
+
+impl ScreenFadeState {
+    pub fn fade_out(
+        transition: FadeTransition,
+        next_state: Box<dyn State<GameData<'static, 'static>, StateEvent>>,
+    ) -> Self {
+        ScreenFadeState {
+            transition,
+            next_state: Some(next_state),
+        }
+    }
+
+    pub fn fade_in(transition: FadeTransition) -> Self {
+        ScreenFadeState {
+            transition,
+            next_state: None,
+        }
+    }
+}
+
+impl SimpleState for ScreenFadeState {
+    fn on_start(&mut self, data: StateData<GameData>) {
+        let (to_alpha, duration) = if self.next_state.is_some() {
+            (1., self.transition.fade_out)
+        } else {
+            (0., self.transition.fade_in)
+        };
+        data.world
+            .write_resource::<ScreenFade>()
+            .start(self.transition.color, to_alpha, duration);
+    }
+
+    fn update(&mut self, data: &mut StateData<GameData>) -> SimpleTrans {
+        data.data.update(&data.world);
+
+        if !data.world.read_resource::<ScreenFade>().is_done() {
+            return Trans::None;
+        }
+        match self.next_state.take() {
+            // the screen is covered, swap the states below it
+            Some(next_state) => Trans::Sequence(vec![
+                Trans::Pop,
+                Trans::Switch(next_state),
+                Trans::Push(Box::new(ScreenFadeState::fade_in(self.transition.clone()))),
+            ]),
+            None => Trans::Pop,
+        }
+    }
+}
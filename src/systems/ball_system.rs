@@ -0,0 +1,143 @@
+// amethyst modules
+use amethyst::{
+    assets::AssetStorage,
+    audio::Source,
+    core::timing::Time,
+    ecs::prelude::{Join, Read, ReadStorage, System, WriteStorage,},
+    ui::UiTransform,
+};
+
+// local modules
+use crate::components::ball_comp::BallComp;
+use crate::components::paddle_comp::{PaddleComp, PaddleSide};
+use crate::components::obstacle_comp::ObstacleComp;
+use crate::resources::play_field::PlayField;
+use crate::resources::settings::Settings;
+use crate::resources::audio::{SfxOutput, SfxRegistry, SFX_PADDLE_HIT, SFX_WALL_HIT, play_sound_panned};
+
+//=============
+// Ball System
+//=============
+pub struct BallSystem;
+
+impl<'s> System<'s> for BallSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Read<'s, PlayField>,
+        WriteStorage<'s, UiTransform>,
+        WriteStorage<'s, BallComp>,
+        ReadStorage<'s, PaddleComp>,
+        ReadStorage<'s, ObstacleComp>,
+        Read<'s, AssetStorage<Source>>,
+        Read<'s, SfxRegistry>,
+        Option<Read<'s, SfxOutput>>,
+        Read<'s, Settings>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (sys_time, field, mut transforms, mut balls, paddles, obstacles, storage, sfx_registry, sfx_output, settings): Self::SystemData) {
+        if !field.is_running {
+            return;
+        }
+
+        // save paddle boxes first, the ball transform is borrowed mutably later
+        let paddle_boxes = (&transforms, &paddles,)
+            .join()
+            .map(|(tran, paddle)| (paddle.clone(), tran.local_x, tran.local_y, tran.width, tran.height))
+            .collect::<Vec<_>>();
+        let obstacle_boxes = (&transforms, &obstacles,)
+            .join()
+            .map(|(tran, _)| (tran.local_x, tran.local_y, tran.width, tran.height))
+            .collect::<Vec<_>>();
+
+        // sounds of the bounces, played at the x position of the ball
+        let mut hit_sounds = Vec::new();
+
+        for (tran, ball,) in (&mut transforms, &mut balls,).join() {
+            // waiting for the serve
+            if ball.is_serving() {
+                tran.local_x = 0.;
+                tran.local_y = 0.;
+                if ball.serve_timer.update(&sys_time) {
+                    ball.launch();
+                }
+                continue;
+            }
+
+            // move the ball
+            tran.local_x += ball.velocity.0 * sys_time.delta_seconds();
+            tran.local_y += ball.velocity.1 * sys_time.delta_seconds();
+
+            // bounce off the left and right borders
+            let radius = tran.width * 0.5;
+            if tran.local_x - radius <= field.left && ball.velocity.0 < 0. {
+                tran.local_x    = field.left + radius;
+                ball.velocity.0 = -ball.velocity.0;
+                hit_sounds.push((SFX_WALL_HIT, tran.local_x));
+            } else if tran.local_x + radius >= field.right && ball.velocity.0 > 0. {
+                tran.local_x    = field.right - radius;
+                ball.velocity.0 = -ball.velocity.0;
+                hit_sounds.push((SFX_WALL_HIT, tran.local_x));
+            }
+
+            // bounce off the obstacles, on the side with the smallest overlap
+            for (obs_x, obs_y, obs_w, obs_h) in obstacle_boxes.iter() {
+                let (dist_x, dist_y) = (tran.local_x - obs_x, tran.local_y - obs_y);
+                let overlap_x = obs_w * 0.5 + radius - dist_x.abs();
+                let overlap_y = obs_h * 0.5 + radius - dist_y.abs();
+                if overlap_x <= 0. || overlap_y <= 0. {
+                    continue;
+                }
+                if overlap_x < overlap_y {
+                    tran.local_x    += overlap_x * dist_x.signum();
+                    ball.velocity.0 = ball.velocity.0.abs() * dist_x.signum();
+                } else {
+                    tran.local_y    += overlap_y * dist_y.signum();
+                    ball.velocity.1 = ball.velocity.1.abs() * dist_y.signum();
+                }
+                hit_sounds.push((SFX_WALL_HIT, tran.local_x));
+            }
+
+            // bounce off the paddles
+            for (paddle, pad_x, pad_y, pad_w, pad_h) in paddle_boxes.iter() {
+                let reach = pad_w * 0.5 + radius;
+                if (tran.local_x - pad_x).abs() > reach {
+                    continue;
+                }
+                // the further from the paddle center, the wider the bounce angle
+                let offset = ((tran.local_x - pad_x) / reach).min(1.).max(-1.);
+                let angle  = paddle.hit_angle(offset).to_radians();
+                match paddle.side {
+                    PaddleSide::Bottom => {
+                        let surface = pad_y + pad_h * 0.5;
+                        if ball.velocity.1 < 0. && tran.local_y - radius <= surface && tran.local_y >= *pad_y {
+                            tran.local_y  = surface + radius;
+                            ball.velocity = (ball.speed * angle.sin(), ball.speed * angle.cos());
+                            hit_sounds.push((SFX_PADDLE_HIT, tran.local_x));
+                        }
+                    }
+                    PaddleSide::Top => {
+                        let surface = pad_y - pad_h * 0.5;
+                        if ball.velocity.1 > 0. && tran.local_y + radius >= surface && tran.local_y <= *pad_y {
+                            tran.local_y  = surface - radius;
+                            ball.velocity = (ball.speed * angle.sin(), -ball.speed * angle.cos());
+                            hit_sounds.push((SFX_PADDLE_HIT, tran.local_x));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (name, x) in hit_sounds {
+            play_sound_panned(
+                name,
+                &sfx_registry,
+                &storage,
+                sfx_output.as_ref().map(|output| &**output),
+                settings.sfx_volume,
+                field.pan(x),
+            );
+        }
+    }
+}
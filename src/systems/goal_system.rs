@@ -0,0 +1,66 @@
+// amethyst modules
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Join, Read, ReadStorage, System, Write, WriteStorage,},
+    ui::UiTransform,
+};
+
+// local modules
+use crate::components::ball_comp::BallComp;
+use crate::components::paddle_comp::PaddleSide;
+use crate::resources::play_field::PlayField;
+use crate::resources::score::Score;
+
+//===========
+// Constants
+//===========
+const SERVE_DELAY: f32 = 1.;    // seconds before the ball is served again after a goal
+
+//=============
+// Goal System
+//=============
+//
+// Detects the goals, updates the score and runs the match clock
+pub struct GoalSystem;
+
+impl<'s> System<'s> for GoalSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Read<'s, PlayField>,
+        Write<'s, Score>,
+        ReadStorage<'s, UiTransform>,
+        WriteStorage<'s, BallComp>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (sys_time, field, mut score, transforms, mut balls): Self::SystemData) {
+        if !field.is_running {
+            return;
+        }
+        score.time_past += sys_time.delta_seconds();
+
+        for (tran, ball,) in (&transforms, &mut balls,).join() {
+            if ball.is_serving() {
+                continue;
+            }
+
+            // the ball has to pass the goal line completely
+            let radius = tran.width * 0.5;
+            let scorer = if tran.local_y - radius > field.top {
+                Some(PaddleSide::Bottom)
+            } else if tran.local_y + radius < field.bottom {
+                Some(PaddleSide::Top)
+            } else {
+                None
+            };
+
+            if let Some(scorer) = scorer {
+                info!("Goal scored by {:?} paddle", scorer);
+                score.add_point(scorer);
+                // serve to the side which lost the point
+                ball.serve(scorer.opponent(), SERVE_DELAY);
+            }
+        }
+    }
+}
@@ -10,9 +10,20 @@
 //
 
 // declare modules
-pub mod ui_glowing_system;
-pub mod ui_swinging_system;
+pub mod ui_effects_system;
 pub mod ps_ui_bundle;
 pub mod ui_cursor_system;
 pub mod ui_waving_system;
-pub mod ui_flashing_system;
\ No newline at end of file
+pub mod ui_flashing_system;
+pub mod ps_game_bundle;
+pub mod paddle_system;
+pub mod ball_system;
+pub mod goal_system;
+pub mod paddle_ai_system;
+pub mod score_hud_system;
+pub mod music_fade_system;
+pub mod ui_progress_bar_system;
+pub mod ui_tween_system;
+pub mod timeline_system;
+pub mod screen_fade_system;
+pub mod ui_visibility_system;
\ No newline at end of file
@@ -0,0 +1,50 @@
+// amethyst modules
+use amethyst::{
+    audio::AudioSink,
+    core::timing::Time,
+    ecs::prelude::{Read, System, Write},
+};
+
+// local modules
+use crate::resources::audio::MusicFade;
+use crate::resources::settings::Settings;
+
+//===================
+// Music Fade System
+//===================
+//
+// Crossfades the previous playlist sink into the current one
+pub struct MusicFadeSystem;
+
+impl<'s> System<'s> for MusicFadeSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Read<'s, Settings>,
+        Write<'s, MusicFade>,
+        Option<Write<'s, AudioSink>>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (sys_time, settings, mut fade, mut sink): Self::SystemData) {
+        if fade.old_sink.is_none() {
+            return;
+        }
+
+        fade.elapsed += sys_time.delta_seconds();
+        let ratio = (fade.elapsed / fade.duration).min(1.);
+        if let Some(ref mut old_sink) = fade.old_sink {
+            old_sink.set_volume(settings.music_volume * (1. - ratio));
+        }
+        if let Some(ref mut sink) = sink {
+            sink.set_volume(settings.music_volume * ratio);
+        }
+
+        // stop the old track once it is silent
+        if ratio >= 1. {
+            if let Some(old_sink) = fade.old_sink.take() {
+                old_sink.stop();
+            }
+        }
+    }
+}
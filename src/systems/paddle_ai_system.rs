@@ -0,0 +1,113 @@
+// external modules
+use rand::random;
+
+// amethyst modules
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Join, Read, ReadStorage, System, WriteStorage,},
+    ui::UiTransform,
+};
+
+// local modules
+use crate::components::ball_comp::BallComp;
+use crate::components::paddle_comp::{PaddleComp, PaddleControl, PaddleSide};
+use crate::components::paddle_ai_comp::PaddleAiComp;
+use crate::resources::play_field::PlayField;
+
+//===========
+// Constants
+//===========
+const SLOW_DOWN_DISTANCE: f32 = 40.;   // the paddle slows down when it gets this close to the target
+
+//==================
+// Paddle AI System
+//==================
+//
+// Drives the input of the cpu paddles by predicting where the ball will cross the paddle line
+pub struct PaddleAiSystem;
+
+impl<'s> System<'s> for PaddleAiSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Read<'s, PlayField>,
+        ReadStorage<'s, UiTransform>,
+        ReadStorage<'s, BallComp>,
+        WriteStorage<'s, PaddleComp>,
+        WriteStorage<'s, PaddleAiComp>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (sys_time, field, transforms, balls, mut paddles, mut ai_items): Self::SystemData) {
+        // (position, radius, velocity, is serving)
+        let ball_state = (&transforms, &balls,)
+            .join()
+            .next()
+            .map(|(tran, ball)| ((tran.local_x, tran.local_y), tran.width * 0.5, ball.velocity, ball.is_serving()));
+
+        for (tran, paddle, ai_item,) in (&transforms, &mut paddles, &mut ai_items,).join() {
+            if paddle.control != PaddleControl::Cpu {
+                continue;
+            }
+            if !field.is_running {
+                paddle.input = 0.;
+                continue;
+            }
+
+            if let Some((ball_pos, radius, velocity, is_serving)) = ball_state {
+                // react to a new ball direction after the reaction delay
+                let approaching = !is_serving && match paddle.side {
+                    PaddleSide::Bottom => velocity.1 < 0.,
+                    PaddleSide::Top    => velocity.1 > 0.,
+                };
+                if approaching != ai_item.approaching {
+                    ai_item.approaching = approaching;
+                    ai_item.reaction_timer.set(ai_item.profile.reaction_delay, false);
+                    ai_item.reaction_timer.start();
+                }
+
+                if ai_item.reaction_timer.is_running() && ai_item.reaction_timer.update(&sys_time) {
+                    ai_item.target_x = if approaching {
+                        // aim at the surface of the paddle
+                        let surface_y = match paddle.side {
+                            PaddleSide::Bottom => tran.local_y + tran.height * 0.5 + radius,
+                            PaddleSide::Top    => tran.local_y - tran.height * 0.5 - radius,
+                        };
+                        let mut target = predict_x(ball_pos, velocity, surface_y, radius, &field)
+                            + (random::<f32>() * 2. - 1.) * ai_item.profile.prediction_error;
+                        // misjudge the ball, it will slip past the paddle edge
+                        if random::<f32>() < ai_item.profile.mistake_chance {
+                            let direction = if random::<f32>() < 0.5 { -1. } else { 1. };
+                            target += direction * (tran.width * 0.5 + radius) * 1.5;
+                        }
+                        target
+                    } else {
+                        // go back to the center while waiting
+                        (field.left + field.right) * 0.5
+                    };
+                }
+            }
+
+            // move toward the target, never faster than the profile allows
+            let distance = ai_item.target_x - tran.local_x;
+            paddle.input = (distance / SLOW_DOWN_DISTANCE).min(1.).max(-1.) * ai_item.profile.max_speed;
+        }
+    }
+}
+
+// x position where the ball will cross the line y, including the bounces off the borders
+fn predict_x(ball_pos: (f32, f32), velocity: (f32, f32), y: f32, radius: f32, field: &PlayField) -> f32 {
+    if velocity.1 == 0. {
+        return ball_pos.0;
+    }
+    let time   = ((y - ball_pos.1) / velocity.1).max(0.);
+    let left   = field.left + radius;
+    let width  = (field.right - radius - left).max(1.);
+    // unfold the bounces: the path is a saw wave with a period of twice the field width
+    let travel = (ball_pos.0 + velocity.0 * time - left).rem_euclid(width * 2.);
+    if travel <= width {
+        left + travel
+    } else {
+        left + width * 2. - travel
+    }
+}
@@ -0,0 +1,70 @@
+// amethyst modules
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Join, Read, System, WriteStorage,},
+    input::{InputHandler, StringBindings},
+    ui::UiTransform,
+};
+
+// local modules
+use crate::components::paddle_comp::PaddleComp;
+use crate::resources::play_field::PlayField;
+
+//===============
+// Paddle System
+//===============
+pub struct PaddleSystem;
+
+impl<'s> System<'s> for PaddleSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Read<'s, InputHandler<StringBindings>>,
+        Read<'s, PlayField>,
+        WriteStorage<'s, UiTransform>,
+        WriteStorage<'s, PaddleComp>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (sys_time, input, field, mut transforms, mut paddles): Self::SystemData) {
+        for (tran, paddle,) in (&mut transforms, &mut paddles,).join() {
+            // read the player input, cpu paddles keep the input given by the ai
+            if let Some(axis_name) = paddle.control.axis_name() {
+                let key_value = input.axis_value(axis_name).unwrap_or(0.);
+                // the stick deflection sets the speed, the keys always go full speed
+                let pad_value = paddle.control
+                    .pad_axis_name()
+                    .and_then(|pad_axis_name| input.axis_value(pad_axis_name))
+                    .unwrap_or(0.);
+                let value = if pad_value.abs() > key_value.abs() { pad_value } else { key_value };
+                paddle.input = value
+                    .min(1.)
+                    .max(-1.);
+            }
+
+            if !field.is_running {
+                paddle.velocity = 0.;
+                continue;
+            }
+
+            // accelerate toward the desired speed
+            let target_velocity = paddle.input * paddle.speed;
+            if paddle.acceleration <= 0. {
+                paddle.velocity = target_velocity;
+            } else {
+                let max_change = paddle.acceleration * sys_time.delta_seconds();
+                paddle.velocity += (target_velocity - paddle.velocity).min(max_change).max(-max_change);
+            }
+
+            // move the paddle and keep it inside the play field
+            let half_width = tran.width * 0.5;
+            let new_x = tran.local_x + paddle.velocity * sys_time.delta_seconds();
+            if new_x <= field.left + half_width || new_x >= field.right - half_width {
+                paddle.velocity = 0.;
+            }
+            tran.local_x = new_x
+                .max(field.left + half_width)
+                .min(field.right - half_width);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::prelude::{DispatcherBuilder, World},
+    error::Error,
+};
+use crate::systems::paddle_ai_system::PaddleAiSystem;
+use crate::systems::paddle_system::PaddleSystem;
+use crate::systems::ball_system::BallSystem;
+use crate::systems::goal_system::GoalSystem;
+use crate::systems::score_hud_system::ScoreHudSystem;
+
+pub struct PsGameBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for PsGameBundle {
+    fn build(self, _world: &mut World, builder: &mut DispatcherBuilder<'a, 'b>) -> Result<(), Error> {
+        builder.add(PaddleAiSystem, "paddle_ai_system", &[]);
+        builder.add(PaddleSystem, "paddle_system", &["paddle_ai_system"]);
+        builder.add(BallSystem, "ball_system", &["paddle_system"]);
+        builder.add(GoalSystem, "goal_system", &["ball_system"]);
+        builder.add(ScoreHudSystem, "score_hud_system", &["goal_system"]);
+        Ok(())
+    }
+}
@@ -3,21 +3,29 @@ use amethyst::{
     ecs::prelude::{DispatcherBuilder, World},
     error::Error,
 };
-use crate::systems::ui_glowing_system::UiGlowingSystem;
-use crate::systems::ui_swinging_system::UiSwingingSystem;
+use crate::systems::ui_effects_system::UiEffectsSystem;
 use crate::systems::ui_cursor_system::UiCursorSystem;
 use crate::systems::ui_waving_system::UiWavingSystem;
 use crate::systems::ui_flashing_system::UiFlashingSystem;
+use crate::systems::ui_progress_bar_system::UiProgressBarSystem;
+use crate::systems::ui_tween_system::UiTweenSystem;
+use crate::systems::timeline_system::TimelineSystem;
+use crate::systems::screen_fade_system::ScreenFadeSystem;
+use crate::systems::ui_visibility_system::UiVisibilitySystem;
 
 pub struct PsUiBundle;
 
 impl<'a, 'b> SystemBundle<'a, 'b> for PsUiBundle {
     fn build(self, _world: &mut World, builder: &mut DispatcherBuilder<'a, 'b>) -> Result<(), Error> {
-        builder.add(UiGlowingSystem, "ui_glowing_system", &[]);
-        builder.add(UiSwingingSystem, "ui_swinging_system", &[]);
-        builder.add(UiCursorSystem, "ui_cursor_system", &[]);
-        builder.add(UiWavingSystem, "ui_waving_system", &[]);
-        builder.add(UiFlashingSystem, "ui_flashing_system", &["ui_glowing_system"]);
+        builder.add(UiEffectsSystem, "ui_effects_system", &[]);
+        builder.add(UiCursorSystem, "ui_cursor_system", &["ui_effects_system"]);
+        builder.add(UiWavingSystem, "ui_waving_system", &["ui_effects_system"]);
+        builder.add(UiVisibilitySystem, "ui_visibility_system", &["ui_effects_system"]);
+        builder.add(UiTweenSystem, "ui_tween_system", &["ui_cursor_system"]);
+        builder.add(UiFlashingSystem, "ui_flashing_system", &["ui_tween_system", "ui_visibility_system"]);
+        builder.add(UiProgressBarSystem, "ui_progress_bar_system", &["ui_effects_system"]);
+        builder.add(TimelineSystem::default(), "timeline_system", &["ui_tween_system", "ui_flashing_system"]);
+        builder.add(ScreenFadeSystem, "screen_fade_system", &["ui_tween_system"]);
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,53 @@
+// amethyst modules
+use amethyst::{
+    ecs::prelude::{Read, System, WriteStorage,},
+    ui::UiText,
+};
+
+// local modules
+use crate::components::paddle_comp::PaddleSide;
+use crate::resources::score::{Score, ScoreHud};
+
+//==================
+// Score HUD System
+//==================
+//
+// Keeps the score and the match timer labels of the game ui up to date,
+// there are no labels out of the game
+pub struct ScoreHudSystem;
+
+impl<'s> System<'s> for ScoreHudSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Score>,
+        Read<'s, ScoreHud>,
+        WriteStorage<'s, UiText>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (score, hud, mut texts): Self::SystemData) {
+        let timer_text = match score.time_remain() {
+            Some(time_remain) => {
+                let seconds = time_remain.ceil() as u32;
+                format!("{}:{:02}", seconds / 60, seconds % 60)
+            }
+            None => "".to_string(),
+        };
+        let labels = [
+            (hud.score_bottom,  score.get_points(PaddleSide::Bottom).to_string()),
+            (hud.score_top,     score.get_points(PaddleSide::Top).to_string()),
+            (hud.match_timer,   timer_text),
+        ];
+
+        for (label, label_text) in labels.iter() {
+            if let Some(label) = label {
+                if let Some(text) = texts.get_mut(*label) {
+                    // only touch the text when it changes, the glyphs are rebuilt on every change
+                    if text.text != *label_text {
+                        text.text = label_text.clone();
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// amethyst modules
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Read, System, Write, WriteStorage,},
+    ui::{UiImage, UiTransform},
+};
+
+// local modules
+use crate::resources::screen_fade::ScreenFade;
+
+//====================
+// Screen Fade System
+//====================
+pub struct ScreenFadeSystem;
+
+impl<'s> System<'s> for ScreenFadeSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Write<'s, ScreenFade>,
+        WriteStorage<'s, UiTransform>,
+        WriteStorage<'s, UiImage>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (sys_time, mut screen_fade, mut transforms, mut images): Self::SystemData) {
+        let overlay = match screen_fade.overlay {
+            Some(overlay) => overlay,
+            None => return,
+        };
+        if !screen_fade.is_done() {
+            screen_fade.elapsed += sys_time.delta_real_seconds();
+        }
+
+        let alpha = screen_fade.alpha();
+        if let Some(UiImage::SolidColor(color)) = images.get_mut(overlay) {
+            *color = [screen_fade.color[0], screen_fade.color[1], screen_fade.color[2], alpha];
+        }
+        // the pointer goes through the overlay once it is gone
+        if let Some(tran) = transforms.get_mut(overlay) {
+            tran.opaque = alpha > 0.;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// amethyst modules
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Read, ReadStorage, System, Write, WriteStorage,},
+    shrev::{EventChannel, ReaderId},
+    ui::{UiFinder, UiTransform},
+};
+
+// local modules
+use crate::components::ui_flashing_comp::UiFlashingComp;
+use crate::components::ui_tween_comp::{UiTweenComp, UiTweenEvent};
+use crate::components::ui_visibility_comp::UiVisibilityComp;
+use crate::resources::timeline::{TimelineAction, TimelineEvent, Timelines};
+
+//=================
+// Timeline System
+//=================
+//
+// Runs the timelines on the real time, the states pause them when they need to
+#[derive(Default)]
+pub struct TimelineSystem {
+    tween_reader:   Option<ReaderId<UiTweenEvent>>,
+}
+
+impl<'s> System<'s> for TimelineSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, Time>,
+        Write<'s, Timelines>,
+        Write<'s, EventChannel<TimelineEvent>>,
+        Write<'s, EventChannel<UiTweenEvent>>,
+        UiFinder<'s>,
+        ReadStorage<'s, UiTransform>,
+        WriteStorage<'s, UiFlashingComp>,
+        WriteStorage<'s, UiTweenComp>,
+        WriteStorage<'s, UiVisibilityComp>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (
+        sys_time,
+        mut timelines,
+        mut timeline_events,
+        mut tween_events,
+        ui_finder,
+        transforms,
+        mut flashings,
+        mut tweens,
+        mut visibilities,
+    ): Self::SystemData) {
+        // the tweens played once that ended last frame, by target id and tween id
+        let tween_reader = self.tween_reader.get_or_insert_with(|| tween_events.register_reader());
+        let ended_tweens = tween_events
+            .read(tween_reader)
+            .filter_map(|event| {
+                transforms.get(event.entity).map(|tran| (tran.id.clone(), event.id.clone()))
+            })
+            .collect::<Vec<_>>();
+
+        let (actions, events) = timelines.update(sys_time.delta_real_seconds(), &ended_tweens);
+
+        for action in actions.iter() {
+            match action {
+                TimelineAction::Flash(target, is_flashing) => {
+                    let entity = ui_finder.find(target);
+                    match entity.and_then(|entity| flashings.get_mut(entity)) {
+                        Some(flashing) => flashing.is_flashing = *is_flashing,
+                        None => warn!("Timeline target {} has no flashing effect", target),
+                    }
+                    if let (Some(entity), true) = (entity, *is_flashing) {
+                        let _insert_result = visibilities.insert(entity, UiVisibilityComp::new(true));
+                    }
+                }
+                TimelineAction::Show(target) | TimelineAction::Hide(target) => {
+                    let is_visible = matches!(action, TimelineAction::Show(_));
+                    match ui_finder.find(target) {
+                        Some(entity) => {
+                            if let Some(flashing) = flashings.get_mut(entity) {
+                                flashing.is_flashing = false;
+                            }
+                            let _insert_result = visibilities.insert(entity, UiVisibilityComp::new(is_visible));
+                        }
+                        None => warn!("Timeline target {} is not found", target),
+                    }
+                }
+                TimelineAction::PlayTween(target, tween_id) => {
+                    let tween = ui_finder
+                        .find(target)
+                        .and_then(|entity| tweens.get_mut(entity))
+                        .and_then(|tween_item| tween_item.tweens.iter_mut().find(|tween| tween.id == *tween_id));
+                    match tween {
+                        Some(tween) => tween.restart(),
+                        None => {
+                            warn!("Timeline target {} has no tween {}", target, tween_id);
+                            timelines.tween_not_found(target, tween_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        for name in events {
+            timeline_events.single_write(TimelineEvent { name });
+        }
+    }
+}
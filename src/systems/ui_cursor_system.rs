@@ -3,7 +3,7 @@ use std::ops::Deref;
 
 // amethyst modules
 use amethyst::{
-    ecs::prelude::{Join, System, WriteStorage, ReadStorage, Read, ReadExpect,},
+    ecs::prelude::{Join, System, WriteStorage, ReadStorage, Read,},
     ui::UiTransform,
     assets::AssetStorage,
     audio::{output::Output, Source},
@@ -13,10 +13,11 @@ use amethyst::{
 use crate::components::ui_cursor_comp::UiCursorComp;
 use crate::components::ui_cursor_option_comp::UiCursorOptionComp;
 use crate::components::ui_cursor_option_comp::UiCursorOptionStyle;
-use crate::components::ui_glowing_comp::UiGlowingComp;
+use crate::components::ui_tween_comp::{UiTweenComp, GLOWING_TWEEN};
 use crate::resources::audio::{
-    play_sound, Sounds, SoundType,
+    play_sound, SfxOutput, SfxRegistry, SFX_CURSOR_TICK,
 };
+use crate::resources::settings::Settings;
 
 //========================
 // Cursor Position System
@@ -29,47 +30,86 @@ impl<'s> System<'s> for UiCursorSystem {
         WriteStorage<'s, UiTransform>,
         WriteStorage<'s, UiCursorComp>,
         ReadStorage<'s, UiCursorOptionComp>,
-        WriteStorage<'s, UiGlowingComp>,
+        WriteStorage<'s, UiTweenComp>,
         Read<'s, AssetStorage<Source>>,
-        ReadExpect<'s, Sounds>,
+        Read<'s, SfxRegistry>,
         Option<Read<'s, Output>>,
+        Option<Read<'s, SfxOutput>>,
+        Read<'s, Settings>,
     );
 
-    fn run(&mut self, (mut trans, mut cursors, options, mut glowings, storage, sounds, audio_output): Self::SystemData) {
-        for (tran, cursor,) in (&mut trans, &mut cursors,).join() {
-            if cursor.pos_list[cursor.current_pos].1 != tran.local_y || cursor.start_up {
-                // move cursor
-                tran.local_y = cursor.pos_list[cursor.current_pos].1;
+    fn run(&mut self, (mut trans, mut cursors, options, mut tweens, storage, sounds, audio_output, sfx_output, settings): Self::SystemData) {
+        // collect the position of every cursor option first,
+        // the cursor itself is moved through the same transform storage
+        let option_positions: Vec<(String, String, f32, f32)> = (&options, &trans,)
+            .join()
+            .map(|(option, tran)| (option.group.clone(), tran.id.clone(), tran.local_x, tran.local_y))
+            .collect();
+
+        // the options to highlight, by group and pointed id
+        let mut highlights: Vec<(String, String)> = Vec::new();
+
+        for (tran, cursor, tween_item) in (&mut trans, &mut cursors, (&mut tweens).maybe()).join() {
+            let pointed_id = match cursor.pos_id_list.get(cursor.current_pos) {
+                Some(pointed_id) => pointed_id,
+                None => continue,
+            };
+            // the option may not be spawned yet
+            let target = option_positions
+                .iter()
+                .find(|(group, id, _, _)| *group == cursor.group && id == pointed_id)
+                .map(|(_, _, x, y)| (x + cursor.offset.0, y + cursor.offset.1));
+            let target = match target {
+                Some(target) => target,
+                None => continue,
+            };
+
+            // follow the option, a swinging cursor swings around its target on its own axis
+            tran.local_x = target.0;
+            tran.local_y = target.1;
+            if let Some(tween_item) = tween_item {
+                tween_item.orginal_pos = target;
+            }
+
+            if cursor.shown_pos != Some(cursor.current_pos) || cursor.start_up {
                 // play sound
                 if !cursor.start_up {
                     play_sound(
-                        SoundType::CursorTick, 
+                        SFX_CURSOR_TICK,
                         &*sounds,
                         &storage,
                         audio_output.as_ref().map(|o| o.deref()),
+                        sfx_output.as_ref().map(|o| o.deref()),
+                        settings.sfx_volume,
                     );
                 } else {
                     cursor.start_up = false;
                 }
-                // highlight option
-                for (option, glowing) in (&options, &mut glowings).join() {
-                    if option.group == cursor.group {
-                        if option.id == cursor.pos_id_list[cursor.current_pos] {
-                            match option.style {
-                                UiCursorOptionStyle::Glowing => {
-                                    glowing.is_glowing = true;
-                                }
+                cursor.shown_pos = Some(cursor.current_pos);
+                highlights.push((cursor.group.clone(), pointed_id.clone()));
+            }
+        }
+
+        // highlight the pointed options, the glowing tween of the others puts their original color back
+        for (group, pointed_id) in highlights {
+            for (option, tween_item) in (&options, &mut tweens).join() {
+                if option.group != group {
+                    continue;
+                }
+                let is_pointed = option.id == pointed_id;
+                match option.style {
+                    UiCursorOptionStyle::Glowing => {
+                        let glowing = tween_item.tweens.iter_mut().find(|tween| tween.id == GLOWING_TWEEN);
+                        if let Some(glowing) = glowing {
+                            if is_pointed && !glowing.is_playing {
+                                glowing.restart();
+                            } else if !is_pointed {
+                                glowing.is_playing = false;
                             }
-                        } else {
-                            match option.style {
-                                UiCursorOptionStyle::Glowing => {
-                                    glowing.is_glowing = false;
-                                }
-                            }   
                         }
                     }
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,167 @@
+// amethyst modules
+use amethyst::{
+    ecs::prelude::{Entities, Join, ReadStorage, System, WriteStorage,},
+    ui::{Interactable, UiImage, UiText, UiTransform},
+};
+
+// local modules
+use crate::components::ui_effects_prefab::UiEffectsComp;
+use crate::components::ui_flashing_comp::UiFlashingComp;
+use crate::components::ui_waving_comp::UiWavingComp;
+use crate::components::ui_cursor_comp::UiCursorComp;
+use crate::components::ui_cursor_option_comp::UiCursorOptionComp;
+use crate::components::ui_progress_bar_comp::UiProgressBarComp;
+use crate::components::ui_tween_comp::{UiTween, UiTweenComp};
+use crate::resources::ui_helper::ui_color;
+
+//===================
+// UI Effects System
+//===================
+//
+// Turns the effects declared in the ui prefabs into effect components,
+// saving the original color and position of the entity on the way.
+// The color is the one of the text or of the solid color image.
+// The glowing and swinging effects are played by the tween component.
+pub struct UiEffectsSystem;
+
+impl<'s> System<'s> for UiEffectsSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, UiEffectsComp>,
+        ReadStorage<'s, UiTransform>,
+        ReadStorage<'s, UiText>,
+        ReadStorage<'s, UiImage>,
+        WriteStorage<'s, UiFlashingComp>,
+        WriteStorage<'s, UiWavingComp>,
+        WriteStorage<'s, UiCursorComp>,
+        WriteStorage<'s, UiCursorOptionComp>,
+        WriteStorage<'s, Interactable>,
+        WriteStorage<'s, UiProgressBarComp>,
+        WriteStorage<'s, UiTweenComp>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (
+        entities,
+        mut effects_items,
+        transforms,
+        texts,
+        images,
+        mut flashings,
+        mut wavings,
+        mut cursors,
+        mut cursor_options,
+        mut interactables,
+        mut progress_bars,
+        mut tweens,
+    ): Self::SystemData) {
+        let mut attached = Vec::new();
+
+        for (entity, effects_item, tran,) in (&entities, &effects_items, &transforms,).join() {
+            let effects     = &effects_item.effects;
+            let org_pos     = (tran.local_x, tran.local_y);
+            let text_color  = ui_color(texts.get(entity), images.get(entity));
+            let is_texture  = texts.get(entity).is_none()
+                && images.get(entity).map_or(false, |image| !matches!(image, UiImage::SolidColor(_)));
+
+            let mut tween_list = effects.tweens
+                .iter()
+                .map(|tween| tween.to_tween())
+                .collect::<Vec<_>>();
+
+            if let Some(ref glowing) = effects.glowing {
+                if is_texture {
+                    warn!("Glowing effect of {} can not tint a texture", tran.id);
+                } else if text_color.is_some() {
+                    tween_list.push(UiTween::glowing(
+                        glowing.rate,
+                        glowing.intensity,
+                        glowing.style,
+                        glowing.rgba_factors,
+                        glowing.is_glowing,
+                    ));
+                } else {
+                    warn!("Glowing effect of {} needs a text or an image", tran.id);
+                }
+            }
+            if let Some(ref flashing) = effects.flashing {
+                if let Some(text_color) = text_color {
+                    let _insert_result = flashings.insert(entity, UiFlashingComp::new(
+                        text_color,
+                        flashing.flashing_color,
+                        flashing.is_flashing,
+                        flashing.rate,
+                        false,
+                    ));
+                } else {
+                    warn!("Flashing effect of {} needs a text or an image", tran.id);
+                }
+            }
+            if let Some(ref swinging) = effects.swinging {
+                tween_list.push(UiTween::swinging(
+                    swinging.rate,
+                    swinging.amplitude,
+                    swinging.style,
+                    swinging.is_swinging,
+                ));
+            }
+            if let Some(ref waving) = effects.waving {
+                let _insert_result = wavings.insert(entity, UiWavingComp::new(
+                    org_pos,
+                    waving.group.clone(),
+                    waving.order,
+                    waving.is_waving,
+                    waving.rate,
+                    waving.height,
+                    waving.low_cut,
+                    waving.high_cut,
+                    waving.delay,
+                    waving.play_time,
+                    waving.wait_time,
+                ));
+            }
+            if let Some(ref cursor) = effects.cursor {
+                let _insert_result = cursors.insert(entity, UiCursorComp::new(
+                    cursor.group.clone(),
+                    0,
+                    cursor.offset,
+                    cursor.options.clone(),
+                    false,
+                    true,
+                ));
+            }
+            if let Some(ref cursor_option) = effects.cursor_option {
+                let _insert_result = cursor_options.insert(entity, UiCursorOptionComp::new(
+                    cursor_option.group.clone(),
+                    tran.id.clone(),
+                    cursor_option.style,
+                    entity,
+                ));
+                // let the pointer hover and click the option
+                let _insert_result = interactables.insert(entity, Interactable);
+            }
+            if let Some(ref progress_bar) = effects.progress_bar {
+                let _insert_result = progress_bars.insert(entity, UiProgressBarComp::new(
+                    org_pos,
+                    tran.width,
+                    progress_bar.style,
+                ));
+            }
+            if !tween_list.is_empty() {
+                let _insert_result = tweens.insert(entity, UiTweenComp::new(
+                    tween_list,
+                    org_pos,
+                    (tran.width, tran.height),
+                    texts.get(entity).map(|text| text.font_size),
+                    text_color,
+                ));
+            }
+            attached.push(entity);
+        }
+
+        for entity in attached {
+            effects_items.remove(entity);
+        }
+    }
+}
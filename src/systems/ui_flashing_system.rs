@@ -1,77 +1,89 @@
 use amethyst::{
-    core::timing::Time,
+    core::{timing::Time, Hidden},
     ecs::prelude::{
+        Entities,
         Read, 
         System, 
         WriteStorage, 
         ReadStorage, 
         Join,
     },
-    ui::UiText,
+    ui::{UiImage, UiText},
 };
 
 // local modules
 use crate::components::ui_flashing_comp::UiFlashingComp;
+use crate::components::ui_visibility_comp::UiHiddenComp;
+use crate::resources::ui_helper::set_ui_color;
 
-//======================
-// Text Flashing System
-//======================
+//=================
+// Flashing System
+//=================
+//
+// Texts and solid color images are tinted, the textures are shown and hidden instead.
+// The entities hidden by their visibility are left alone.
 pub struct UiFlashingSystem;
 
 impl<'s> System<'s> for UiFlashingSystem {
     // define what data to be retreived from the storage
     type SystemData = (
+        Entities<'s>,
         Read<'s, Time>, 
         WriteStorage<'s, UiText>,
+        WriteStorage<'s, UiImage>,
+        WriteStorage<'s, Hidden>,
         ReadStorage<'s, UiFlashingComp>,
+        ReadStorage<'s, UiHiddenComp>,
     );
 
     // system execution (run every frame)
-    fn run(&mut self, (sys_time, mut texts, flashing_items): Self::SystemData) {
+    fn run(&mut self, (entities, sys_time, mut texts, mut images, mut hiddens, flashing_items, hidden_items): Self::SystemData) {
         //--------------------------
         // flashing some ui element 
         //--------------------------
-        for (text, flashing_item,) in (&mut texts, &flashing_items,).join() {
-            if flashing_item.is_hiding {
-                // Use original color 
-                text.color = [
-                    flashing_item.orginal_color[0], 
-                    flashing_item.orginal_color[1], 
-                    flashing_item.orginal_color[2], 
-                    0.,
-                ];     
-            } else if flashing_item.is_flashing {
+        for (entity, text, image, flashing_item, _) in (
+            &entities,
+            (&mut texts).maybe(),
+            (&mut images).maybe(),
+            &flashing_items,
+            !&hidden_items,
+        ).join() {
+            let color = if flashing_item.is_flashing {
                 // calculate the flashing factor based on the rate
                 let should_be_on = (
                             sys_time.absolute_real_time_seconds() as f32 * 25. * flashing_item.rate
                         ).sin() >= 0.;
                 if should_be_on {
-                    text.color = [
-                        flashing_item.flashing_color[0], 
-                        flashing_item.flashing_color[1], 
-                        flashing_item.flashing_color[2], 
-                        flashing_item.flashing_color[3],
-                    ];  
+                    Some(flashing_item.flashing_color)
                 } else {
-                    text.color = [
+                    Some([
                         flashing_item.orginal_color[0], 
                         flashing_item.orginal_color[1], 
                         flashing_item.orginal_color[2], 
                         0.,
-                    ];    
-                   
-                }              
-            } else {
+                    ])
+                }
+            } else if flashing_item.reset_color {
                 // reset to original color
-                if flashing_item.reset_color {
-                    text.color = [
-                        flashing_item.orginal_color[0], 
-                        flashing_item.orginal_color[1], 
-                        flashing_item.orginal_color[2], 
-                        flashing_item.orginal_color[3],
-                    ]; 
+                Some(flashing_item.orginal_color)
+            } else {
+                None
+            };
+
+            if let Some(color) = color {
+                let is_tinted = set_ui_color(text, image, color);
+                // a texture can only be on or off
+                if !is_tinted {
+                    if color[3] > 0. {
+                        hiddens.remove(entity);
+                    } else {
+                        let _insert_result = hiddens.insert(entity, Hidden);
+                    }
                 }
+            } else if text.is_none() {
+                // a texture stopped on its off blink is shown again
+                hiddens.remove(entity);
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,46 @@
+// amethyst modules
+use amethyst::{
+    ecs::prelude::{Join, Read, ReadStorage, System, WriteStorage,},
+    ui::{UiText, UiTransform},
+};
+
+// local modules
+use crate::components::ui_progress_bar_comp::{UiProgressBarComp, UiProgressBarStyle};
+use crate::resources::loading_progress::LoadingProgress;
+
+//========================
+// UI Progress Bar System
+//========================
+pub struct UiProgressBarSystem;
+
+impl<'s> System<'s> for UiProgressBarSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Read<'s, LoadingProgress>,
+        ReadStorage<'s, UiProgressBarComp>,
+        WriteStorage<'s, UiTransform>,
+        WriteStorage<'s, UiText>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (progress, progress_bars, mut transforms, mut texts): Self::SystemData) {
+        let ratio = progress.ratio();
+        for (progress_bar, tran, text) in (&progress_bars, &mut transforms, (&mut texts).maybe()).join() {
+            match progress_bar.style {
+                UiProgressBarStyle::Bar => {
+                    // keep the left edge in place while the bar grows
+                    tran.width   = progress_bar.full_width * ratio;
+                    tran.local_x = progress_bar.orginal_pos.0 - (progress_bar.full_width - tran.width) * 0.5;
+                }
+                UiProgressBarStyle::Percentage => {
+                    if let Some(text) = text {
+                        let percentage = format!("{}%", (ratio * 100.).floor());
+                        if text.text != percentage {
+                            text.text = percentage;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
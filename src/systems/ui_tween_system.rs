@@ -0,0 +1,156 @@
+// amethyst modules
+use amethyst::{
+    core::timing::Time,
+    ecs::prelude::{Entities, Join, Read, System, Write, WriteStorage,},
+    shrev::EventChannel,
+    ui::{UiImage, UiText, UiTransform},
+};
+
+// local modules
+use crate::components::ui_tween_comp::{UiTweenComp, UiTweenEvent, UiTweenMode, UiTweenProperty};
+
+//=================
+// UI Tween System
+//=================
+//
+// Runs on the real time like the other ui effects, so the tweens keep playing during a pause.
+// The glowing and swinging effects of the prefabs are played as tweens as well.
+pub struct UiTweenSystem;
+
+impl<'s> System<'s> for UiTweenSystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Entities<'s>,
+        Read<'s, Time>,
+        Write<'s, EventChannel<UiTweenEvent>>,
+        WriteStorage<'s, UiTweenComp>,
+        WriteStorage<'s, UiTransform>,
+        WriteStorage<'s, UiText>,
+        WriteStorage<'s, UiImage>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (
+        entities,
+        sys_time,
+        mut tween_events,
+        mut tween_items,
+        mut transforms,
+        mut texts,
+        mut images,
+    ): Self::SystemData) {
+        let delta = sys_time.delta_real_seconds();
+        for (entity, tween_item, tran, mut text, mut image) in (
+            &entities,
+            &mut tween_items,
+            &mut transforms,
+            (&mut texts).maybe(),
+            (&mut images).maybe(),
+        ).join() {
+            let orginal_pos     = tween_item.orginal_pos;
+            let orginal_size    = tween_item.orginal_size;
+            let orginal_font    = tween_item.orginal_font;
+            let orginal_color   = tween_item.orginal_color;
+
+            for tween in tween_item.tweens.iter_mut() {
+                // a tween played once keeps its end,
+                // a looping one puts the original back in the frame it is stopped and is left alone afterwards
+                let is_resting = !tween.is_playing;
+                let was_playing = tween.was_playing;
+                tween.was_playing = tween.is_playing;
+                if is_resting && (!was_playing || tween.mode == UiTweenMode::Once) {
+                    continue;
+                }
+
+                // move the time forward, one loop or one way at most per frame
+                if !is_resting {
+                    tween.elapsed += delta;
+                }
+                if !is_resting && tween.elapsed - tween.delay >= tween.duration {
+                    match tween.mode {
+                        UiTweenMode::Once => {
+                            tween.elapsed    = tween.delay + tween.duration;
+                            tween.is_playing = false;
+                            tween_events.single_write(UiTweenEvent { entity, id: tween.id.clone() });
+                        }
+                        UiTweenMode::Loop => {
+                            tween.elapsed -= tween.duration.max(delta);
+                        }
+                        UiTweenMode::PingPong => {
+                            tween.elapsed     -= tween.duration.max(delta);
+                            tween.is_reversed = !tween.is_reversed;
+                        }
+                    }
+                }
+
+                // apply the eased value, or the original one
+                let progress = tween.progress();
+                let lerp = |from: f32, to: f32| from + (to - from) * progress;
+                let color = match tween.property {
+                    UiTweenProperty::Position { from, to } => {
+                        let offset = if is_resting { (0., 0.) } else { (lerp(from.0, to.0), lerp(from.1, to.1)) };
+                        tran.local_x = orginal_pos.0 + offset.0;
+                        tran.local_y = orginal_pos.1 + offset.1;
+                        None
+                    }
+                    UiTweenProperty::Scale { from, to } => {
+                        let scale = if is_resting { 1. } else { lerp(from, to).max(0.) };
+                        tran.width  = orginal_size.0 * scale;
+                        tran.height = orginal_size.1 * scale;
+                        if let (Some(text), Some(orginal_font)) = (text.as_mut(), orginal_font) {
+                            text.font_size = orginal_font * scale;
+                        }
+                        None
+                    }
+                    UiTweenProperty::Color { from, to } => {
+                        if is_resting {
+                            orginal_color
+                        } else {
+                            Some([
+                                lerp(from[0], to[0]).min(1.).max(0.),   // R
+                                lerp(from[1], to[1]).min(1.).max(0.),   // G
+                                lerp(from[2], to[2]).min(1.).max(0.),   // B
+                                lerp(from[3], to[3]).min(1.).max(0.),   // A
+                            ])
+                        }
+                    }
+                    UiTweenProperty::Alpha { from, to } => {
+                        let alpha = if is_resting {
+                            orginal_color.map(|color| color[3])
+                        } else {
+                            Some(lerp(from, to).min(1.).max(0.))
+                        };
+                        if let Some(alpha) = alpha {
+                            if let Some(text) = text.as_mut() {
+                                text.color[3] = alpha;
+                            }
+                            if let Some(UiImage::SolidColor(image_color)) = image.as_mut().map(|image| &mut **image) {
+                                image_color[3] = alpha;
+                            }
+                        }
+                        None
+                    }
+                    UiTweenProperty::Tint { from, to } => {
+                        orginal_color.map(|orginal_color| {
+                            let tint = |channel: usize| if is_resting { 0. } else { lerp(from[channel], to[channel]) };
+                            [
+                                (orginal_color[0] + tint(0)).min(1.).max(0.),   // R
+                                (orginal_color[1] + tint(1)).min(1.).max(0.),   // G
+                                (orginal_color[2] + tint(2)).min(1.).max(0.),   // B
+                                (orginal_color[3] + tint(3)).min(1.).max(0.),   // A
+                            ]
+                        })
+                    }
+                };
+                if let Some(color) = color {
+                    if let Some(text) = text.as_mut() {
+                        text.color = color;
+                    }
+                    if let Some(UiImage::SolidColor(image_color)) = image.as_mut().map(|image| &mut **image) {
+                        *image_color = color;
+                    }
+                }
+            }
+        }
+    }
+}
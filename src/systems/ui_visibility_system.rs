@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+// amethyst modules
+use amethyst::{
+    core::{Hidden, ParentHierarchy},
+    ecs::prelude::{
+        Entities,
+        Entity,
+        Join,
+        ReadExpect,
+        ReadStorage,
+        System,
+        WriteStorage,
+    },
+    ui::Interactable,
+};
+
+// local modules
+use crate::components::ui_visibility_comp::{UiHiddenComp, UiVisibilityComp};
+
+//===================
+// Visibility System
+//===================
+//
+// A hidden subtree is not drawn and does not react to the pointer,
+// the entities get back their interaction when they are shown again
+pub struct UiVisibilitySystem;
+
+impl<'s> System<'s> for UiVisibilitySystem {
+    // define what data to be retreived from the storage
+    type SystemData = (
+        Entities<'s>,
+        ReadExpect<'s, ParentHierarchy>,
+        ReadStorage<'s, UiVisibilityComp>,
+        WriteStorage<'s, UiHiddenComp>,
+        WriteStorage<'s, Hidden>,
+        WriteStorage<'s, Interactable>,
+    );
+
+    // system execution (run every frame)
+    fn run(&mut self, (
+        entities,
+        hierarchy,
+        visibilities,
+        mut hidden_items,
+        mut hiddens,
+        mut interactables,
+    ): Self::SystemData) {
+        // the hidden entities with all their children
+        let mut hidden_entities: HashSet<Entity> = HashSet::new();
+        for (entity, visibility) in (&entities, &visibilities).join() {
+            if !visibility.is_visible {
+                hidden_entities.insert(entity);
+                hidden_entities.extend(hierarchy.all_children_iter(entity));
+            }
+        }
+
+        // hide the entities hidden since last frame
+        for entity in hidden_entities.iter() {
+            if hidden_items.contains(*entity) {
+                continue;
+            }
+            let was_interactable = interactables.remove(*entity).is_some();
+            let _insert_result = hidden_items.insert(*entity, UiHiddenComp::new(was_interactable));
+            let _insert_result = hiddens.insert(*entity, Hidden);
+        }
+
+        // show the entities that are not hidden anymore
+        let shown_entities = (&entities, &hidden_items)
+            .join()
+            .filter(|(entity, _)| !hidden_entities.contains(entity))
+            .map(|(entity, hidden_item)| (entity, hidden_item.was_interactable))
+            .collect::<Vec<_>>();
+        for (entity, was_interactable) in shown_entities {
+            hidden_items.remove(entity);
+            hiddens.remove(entity);
+            if was_interactable {
+                let _insert_result = interactables.insert(entity, Interactable);
+            }
+        }
+    }
+}